@@ -9,6 +9,9 @@ use syn::{parse2, Attribute, Expr, ExprLit, Lit, Meta, Path, Result, Token, Type
 enum BitcodeAttr {
     BoundType(Type),
     CrateName(Path),
+    ProjectFrom(Type),
+    Recursive,
+    Serde,
     Skip,
 }
 
@@ -54,6 +57,24 @@ impl BitcodeAttr {
                 }
                 _ => err(&nested, "expected name value"),
             },
+            "project_from" => match nested {
+                Meta::NameValue(name_value) => {
+                    let expr = &name_value.value;
+                    let str_lit = match expr {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(v), ..
+                        }) => v,
+                        _ => return err(&expr, "expected type string e.g. \"FullRecord\""),
+                    };
+
+                    let ty = syn::parse_str::<Type>(&str_lit.value())
+                        .map_err(|e| error(str_lit, &e.to_string()))?;
+                    Ok(Self::ProjectFrom(ty))
+                }
+                _ => err(&nested, "expected name value"),
+            },
+            "recursive" => Ok(Self::Recursive),
+            "serde" => Ok(Self::Serde),
             "skip" => Ok(Self::Skip),
             _ => err(&nested, "unknown attribute"),
         }
@@ -90,6 +111,27 @@ impl BitcodeAttr {
                     err(nested, r#"can only apply to struct/enum definition"#)
                 }
             }
+            Self::ProjectFrom(project_from) => {
+                if let BitcodeAnyAttrs::Derive(derive) = attrs {
+                    set_if_not_duplicate(&mut derive.project_from, Some(project_from), nested)
+                } else {
+                    err(nested, r#"can only apply to struct definition"#)
+                }
+            }
+            Self::Recursive => {
+                if let BitcodeAnyAttrs::Field(field) = attrs {
+                    set_if_not_duplicate(&mut field.recursive, true, nested)
+                } else {
+                    err(nested, "can only apply to fields")
+                }
+            }
+            Self::Serde => {
+                if let BitcodeAnyAttrs::Field(field) = attrs {
+                    set_if_not_duplicate(&mut field.serde, true, nested)
+                } else {
+                    err(nested, "can only apply to fields")
+                }
+            }
             Self::Skip => {
                 if let BitcodeAnyAttrs::Field(field) = attrs {
                     set_if_not_duplicate(&mut field.skip, true, nested)
@@ -103,12 +145,15 @@ impl BitcodeAttr {
 
 pub struct BitcodeDeriveAttrs {
     crate_name: Option<Path>,
+    /// `#[bitcode(project_from = "FullRecord")]` decodes a subset of `FullRecord`'s fields.
+    pub project_from: Option<Type>,
     pub private: TokenStream,
 }
 impl BitcodeDeriveAttrs {
     pub fn parse(attrs: &[Attribute]) -> Result<Self> {
         let mut ret = Self {
             crate_name: Default::default(),
+            project_from: Default::default(),
             private: quote! {},
         };
         BitcodeAnyAttrs::Derive(&mut ret).parse_inner(attrs)?;
@@ -158,6 +203,8 @@ impl std::ops::Deref for BitcodeDeriveOrVariantAttrs<'_> {
 pub struct BitcodeFieldAttrs<'a> {
     parent: BitcodeDeriveOrVariantAttrs<'a>,
     pub bound_type: Option<Type>,
+    pub recursive: bool,
+    pub serde: bool,
     pub skip: bool,
 }
 impl<'a> std::ops::Deref for BitcodeFieldAttrs<'a> {
@@ -171,6 +218,8 @@ impl<'a> BitcodeFieldAttrs<'a> {
         let mut ret = Self {
             parent,
             bound_type: Default::default(),
+            recursive: Default::default(),
+            serde: Default::default(),
             skip: Default::default(),
         };
         BitcodeAnyAttrs::Field(&mut ret).parse_inner(attrs)?;
@@ -17,17 +17,31 @@ pub enum Item {
     Type,
     Default,
     Populate,
+    PopulateParallel,
+    HeapSize,
+    SetRecursionDepth,
     Decode,
     DecodeInPlace,
+    DecodeInto,
+    Seekable,
+    Seek,
+    Project,
 }
 
 impl Item {
-    const ALL: [Self; 4] = [
+    const ALL: [Self; 11] = [
         Self::Type,
         Self::Default,
         Self::Populate,
+        Self::PopulateParallel,
+        Self::HeapSize,
+        Self::SetRecursionDepth,
         // No Self::Decode since it's only used for enum variants, not top level struct/enum.
         Self::DecodeInPlace,
+        Self::DecodeInto,
+        Self::Seekable,
+        Self::Seek,
+        Self::Project,
     ];
     const COUNT: usize = Self::ALL.len();
 }
@@ -42,15 +56,21 @@ impl crate::shared::Item for Item {
         field_type: &Type,
     ) -> TokenStream {
         match self {
+            // project_from structs only have a decoder for the whole project_from type.
+            Self::Type
+            | Self::Default
+            | Self::Populate
+            | Self::PopulateParallel
+            | Self::HeapSize
+            | Self::SetRecursionDepth
+                if attrs.project_from.is_some() =>
+            {
+                quote! {}
+            }
             Self::Type => {
-                let mut de_type = replace_lifetimes(field_type, DE_LIFETIME).to_token_stream();
-                if attrs.skip {
-                    de_type = quote! { ::core::marker::PhantomData<#de_type> };
-                }
-                let private = &attrs.private;
-                let de = de_lifetime();
+                let decoder_type = field_decoder_type(attrs, field_type);
                 quote! {
-                    #global_field_name: <#de_type as #private::Decode<#de>>::Decoder,
+                    #global_field_name: #decoder_type,
                 }
             }
             Self::Default => quote! {
@@ -59,6 +79,15 @@ impl crate::shared::Item for Item {
             Self::Populate => quote! {
                 self.#global_field_name.populate(input, __length)?;
             },
+            Self::PopulateParallel => quote! {
+                self.#global_field_name.populate_parallel(input, __length, __tasks)?;
+            },
+            Self::HeapSize => quote! {
+                + self.#global_field_name.heap_size()
+            },
+            Self::SetRecursionDepth => quote! {
+                self.#global_field_name.set_recursion_depth(__depth);
+            },
             // Only used by enum variants.
             Self::Decode => {
                 let value = if attrs.skip {
@@ -85,8 +114,67 @@ impl crate::shared::Item for Item {
                         (#target).write(Default::default());
                     }}
                 } else {
+                    let decoder = field_decoder(attrs, &global_field_name, &real_field_name);
                     quote! {
-                        self.#global_field_name.decode_in_place(#target);
+                        #decoder.decode_in_place(#target);
+                    }
+                }
+            }
+            // #field_name is a &mut to the field (see struct_impl and enum_impl).
+            Self::DecodeInto => {
+                if attrs.skip {
+                    return quote! {
+                        *#field_name = Default::default();
+                    };
+                }
+                let decoder = field_decoder(attrs, &global_field_name, &real_field_name);
+                let de_type = replace_lifetimes(field_type, DE_LIFETIME);
+                if &de_type == field_type {
+                    return quote! {
+                        #decoder.decode_into(#field_name);
+                    };
+                }
+                // Like DecodeInPlace, the field's lifetimes are cast to 'de. Decoders only drop or
+                // overwrite the existing value, so it's never used as if it lived for 'de.
+                quote! {
+                    #decoder.decode_into(unsafe {
+                        &mut *(#field_name as *mut _ as *mut #de_type)
+                    });
+                }
+            }
+            // Skipped fields don't have any columns.
+            Self::Seekable | Self::Seek if attrs.skip => quote! {},
+            Self::Seekable => {
+                if attrs.serde || attrs.recursive {
+                    return quote! { && false };
+                }
+                let de_type = replace_lifetimes(field_type, DE_LIFETIME);
+                let private = &attrs.private;
+                let de = de_lifetime();
+                quote! {
+                    && <<#de_type as #private::Decode<#de>>::Decoder as #private::Decoder<#de, #de_type>>::SEEKABLE
+                }
+            }
+            Self::Seek => {
+                let de_type = replace_lifetimes(field_type, DE_LIFETIME);
+                let private = &attrs.private;
+                let de = de_lifetime();
+                quote! {
+                    #private::Decoder::<#de, #de_type>::seek(&mut self.#global_field_name, __n);
+                }
+            }
+            // Accessors for structs which #[bitcode(project_from)] this struct.
+            Self::Project => {
+                if attrs.skip || attrs.project_from.is_some() {
+                    return quote! {};
+                }
+                let accessor = project_accessor(&real_field_name);
+                let decoder_type = field_decoder_type(attrs, field_type);
+                quote! {
+                    #[doc(hidden)]
+                    #[inline(always)]
+                    pub fn #accessor(&mut self) -> &mut #decoder_type {
+                        &mut self.#global_field_name
                     }
                 }
             }
@@ -95,12 +183,45 @@ impl crate::shared::Item for Item {
 
     fn struct_impl(
         self,
-        _ident: &Ident,
-        _destructure_fields: &TokenStream,
+        attrs: &BitcodeDeriveAttrs,
+        ident: &Ident,
+        destructure_fields: &TokenStream,
         do_fields: &TokenStream,
     ) -> TokenStream {
+        if let Some(project_from) = &attrs.project_from {
+            // Populating the whole decoder validates every column, but only the projected fields
+            // are decoded.
+            match self {
+                Self::Type => {
+                    let full_type = replace_lifetimes(project_from, DE_LIFETIME);
+                    let private = &attrs.private;
+                    let de = de_lifetime();
+                    return quote! {
+                        __full: <#full_type as #private::Decode<#de>>::Decoder,
+                    };
+                }
+                Self::Default => return quote! { __full: Default::default(), },
+                Self::Populate => return quote! { self.__full.populate(input, __length)?; },
+                Self::PopulateParallel => {
+                    return quote! { self.__full.populate_parallel(input, __length, __tasks)?; }
+                }
+                Self::HeapSize => return quote! { + self.__full.heap_size() },
+                Self::SetRecursionDepth => {
+                    return quote! { self.__full.set_recursion_depth(__depth); }
+                }
+                // The unused columns don't advance, so they can't be seeked.
+                Self::Seekable => return quote! { false },
+                Self::Seek => return quote! {},
+                _ => {}
+            }
+        }
         match self {
             Self::Decode => unimplemented!(),
+            Self::DecodeInto => quote! {
+                let #ident #destructure_fields = out;
+                #do_fields
+            },
+            Self::Seekable => quote! { true #do_fields },
             _ => quote! { #do_fields },
         }
     }
@@ -148,7 +269,7 @@ impl crate::shared::Item for Item {
                     #inners
                 }
             }
-            Self::Populate => {
+            Self::Populate | Self::PopulateParallel => {
                 if never {
                     let private = &attrs.private;
                     return quote! {
@@ -158,6 +279,7 @@ impl crate::shared::Item for Item {
                     };
                 }
 
+                // The variants are needed for the lengths of the fields, so they can't be deferred.
                 let variants = decode_variants
                     .then(|| {
                         quote! { self.variants.populate(input, __length)?; }
@@ -189,6 +311,19 @@ impl crate::shared::Item for Item {
                     #inners
                 }
             }
+            Self::HeapSize => {
+                let variants = if decode_variants {
+                    quote! { + self.variants.heap_size() }
+                } else {
+                    quote! {}
+                };
+                let inners: TokenStream = (0..variant_count).map(|i| inner(self, i)).collect();
+                quote! {
+                    #variants
+                    #inners
+                }
+            }
+            Self::SetRecursionDepth => (0..variant_count).map(|i| inner(self, i)).collect(),
             Self::Decode => unimplemented!(),
             Self::DecodeInPlace => {
                 if never {
@@ -242,6 +377,85 @@ impl crate::shared::Item for Item {
                     })
                     .unwrap_or_default()
             }
+            Self::DecodeInto => {
+                if never {
+                    return quote! {
+                        // Safety: View::populate will error on length != 0 so decode won't be called.
+                        unsafe { ::core::hint::unreachable_unchecked() }
+                    };
+                }
+
+                if !decode_variants {
+                    // The only variant, so reuse it.
+                    let inner = inner(self, 0);
+                    let pattern = pattern(0);
+                    return quote! {
+                        let #pattern = out;
+                        #inner
+                    };
+                }
+
+                let variants: TokenStream = (0..variant_count)
+                    .map(|i| {
+                        let inner_into = inner(self, i);
+                        let pattern = pattern(i);
+                        // Reuse the existing variant if it matches, otherwise decode a new one.
+                        let body = if inner_into.is_empty() {
+                            quote! {
+                                *out = #pattern;
+                            }
+                        } else {
+                            let inner = inner(Self::Decode, i);
+                            quote! {
+                                if let #pattern = out {
+                                    #inner_into
+                                } else {
+                                    #inner
+                                    *out = #pattern;
+                                }
+                            }
+                        };
+                        let i = variant_index_type.instance_to_tokens(i);
+                        quote! {
+                            #i => {
+                                #body
+                            },
+                        }
+                    })
+                    .collect();
+                quote! {
+                    match self.variants.decode() {
+                        #variants
+                        // Safety: VariantDecoder<_, N, _>::decode outputs numbers less than N.
+                        _ => unsafe { ::core::hint::unreachable_unchecked() }
+                    }
+                }
+            }
+            Self::Seekable => {
+                if never {
+                    return quote! { true };
+                }
+                if !decode_variants {
+                    let inner = inner(self, 0);
+                    return quote! { true #inner };
+                }
+                // Variants with fields have a variable number of items in their columns.
+                let c_style = (0..variant_count).all(|i| inner(Self::Seek, i).is_empty());
+                quote! { #c_style }
+            }
+            Self::Seek => {
+                if never {
+                    return quote! {};
+                }
+                if !decode_variants {
+                    return inner(self, 0);
+                }
+                quote! {
+                    self.variants.seek(__n);
+                }
+            }
+            // Enums can't be projected.
+            Self::Project => quote! {},
         }
     }
 }
@@ -261,6 +475,16 @@ impl crate::shared::Derive<{ Item::COUNT }> for Decode {
         Some(parse_quote!(Default))
     }
 
+    fn serde_bound(&self, attrs: &BitcodeDeriveAttrs) -> Path {
+        let private = &attrs.private;
+        let de = de_lifetime();
+        parse_quote!(#private::DeserializeSendSync<#de>)
+    }
+
+    fn supports_project_from(&self) -> bool {
+        true
+    }
+
     fn derive_impl(
         &self,
         attrs: &BitcodeDeriveAttrs,
@@ -309,7 +533,8 @@ impl crate::shared::Derive<{ Item::COUNT }> for Decode {
         let (decoder_impl_generics, decoder_generics, decoder_where_clause) =
             generics.split_for_impl();
 
-        let [mut type_body, mut default_body, populate_body, decode_in_place_body] = output;
+        let [mut type_body, mut default_body, populate_body, populate_parallel_body, heap_size_body, set_recursion_depth_body, decode_in_place_body, decode_into_body, seekable_body, seek_body, project_body] =
+            output;
         if type_body.is_empty() {
             type_body = quote! { __spooky: ::core::marker::PhantomData<&#de ()>, };
         }
@@ -347,6 +572,33 @@ impl crate::shared::Derive<{ Item::COUNT }> for Decode {
                         #populate_body
                         Ok(())
                     }
+
+                    fn heap_size(&self) -> usize {
+                        0 #heap_size_body
+                    }
+
+                    fn set_recursion_depth(&mut self, __depth: usize) {
+                        #set_recursion_depth_body
+                    }
+
+                    #private::if_rayon! {
+                        fn populate_parallel<'__s>(
+                            &'__s mut self,
+                            input: &mut &#de [u8],
+                            __length: usize,
+                            #[allow(unused)] __tasks: &mut #private::ParallelInput<'__s>,
+                        ) -> #private::Result<()>
+                        where
+                            #de: '__s,
+                        {
+                            #populate_parallel_body
+                            Ok(())
+                        }
+                    }
+                }
+
+                impl #decoder_impl_generics #decoder_ty #decoder_where_clause {
+                    #project_body
                 }
 
                 impl #impl_generics #private::Decoder<#de, #input_ty> for #decoder_ty #where_clause {
@@ -354,8 +606,66 @@ impl crate::shared::Derive<{ Item::COUNT }> for Decode {
                     fn decode_in_place(&mut self, out: &mut ::core::mem::MaybeUninit<#input_ty>) {
                         #decode_in_place_body
                     }
+
+                    #[cfg_attr(not(debug_assertions), inline(always))]
+                    fn decode_into(&mut self, out: &mut #input_ty) {
+                        #decode_into_body
+                    }
+
+                    const SEEKABLE: bool = #seekable_body;
+
+                    #[cfg_attr(not(debug_assertions), inline(always))]
+                    #[allow(unused_unsafe)]
+                    unsafe fn seek(&mut self, #[allow(unused)] __n: isize) {
+                        unsafe {
+                            #seek_body
+                        }
+                    }
                 }
             };
         }
     }
 }
+
+/// The type of a field's decoder, e.g. `<T as Decode<'__de>>::Decoder`.
+fn field_decoder_type(attrs: &BitcodeFieldAttrs, field_type: &Type) -> TokenStream {
+    let mut de_type = replace_lifetimes(field_type, DE_LIFETIME).to_token_stream();
+    if attrs.skip {
+        de_type = quote! { ::core::marker::PhantomData<#de_type> };
+    }
+    let private = &attrs.private;
+    let de = de_lifetime();
+    if attrs.serde {
+        quote! { #private::DeserializeDecoder<#de, #de_type> }
+    } else if attrs.recursive {
+        quote! { #private::RecursiveDecoder<#de, #de_type> }
+    } else {
+        quote! { <#de_type as #private::Decode<#de>>::Decoder }
+    }
+}
+
+/// A field's decoder, which is in the project_from type's decoder for project_from structs.
+fn field_decoder(
+    attrs: &BitcodeFieldAttrs,
+    global_field_name: &TokenStream,
+    real_field_name: &TokenStream,
+) -> TokenStream {
+    if attrs.project_from.is_some() {
+        let accessor = project_accessor(real_field_name);
+        quote! { self.__full.#accessor() }
+    } else {
+        quote! { self.#global_field_name }
+    }
+}
+
+/// The name of the decoder method which returns a field's decoder (see `Item::Project`).
+fn project_accessor(real_field_name: &TokenStream) -> Ident {
+    let name = real_field_name.to_string();
+    let name = name.strip_prefix("r#").unwrap_or(&name);
+    let span = real_field_name
+        .clone()
+        .into_iter()
+        .next()
+        .map_or_else(Span::call_site, |t| t.span());
+    Ident::new(&format!("__bitcode_project_{name}"), span)
+}
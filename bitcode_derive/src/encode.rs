@@ -11,16 +11,28 @@ pub enum Item {
     Encode,
     EncodeVectored,
     CollectInto,
+    CollectIntoParallel,
     Reserve,
+    HeapSize,
+    EncodedLen,
+    Clear,
+    ShrinkToFit,
+    MaxEncodedLen,
 }
 impl Item {
-    const ALL: [Self; 6] = [
+    const ALL: [Self; 12] = [
         Self::Type,
         Self::Default,
         Self::Encode,
         Self::EncodeVectored,
         Self::CollectInto,
+        Self::CollectIntoParallel,
         Self::Reserve,
+        Self::HeapSize,
+        Self::EncodedLen,
+        Self::Clear,
+        Self::ShrinkToFit,
+        Self::MaxEncodedLen,
     ];
     const COUNT: usize = Self::ALL.len();
 }
@@ -35,11 +47,21 @@ impl crate::shared::Item for Item {
     ) -> TokenStream {
         match self {
             Self::Type => {
+                let private = &attrs.private;
+                if attrs.serde {
+                    return quote! {
+                        #global_field_name: #private::SerializeEncoder,
+                    };
+                }
                 let mut static_type = replace_lifetimes(field_type, "static").to_token_stream();
+                if attrs.recursive {
+                    return quote! {
+                        #global_field_name: #private::RecursiveEncoder<#static_type>,
+                    };
+                }
                 if attrs.skip {
                     static_type = quote! { ::core::marker::PhantomData<#static_type> };
                 }
-                let private = &attrs.private;
                 quote! {
                     #global_field_name: <#static_type as #private::Encode>::Encoder,
                 }
@@ -56,6 +78,9 @@ impl crate::shared::Item for Item {
                             &::core::marker::PhantomData::<#static_type>
                         }
                     }
+                } else if attrs.serde {
+                    // SerializeEncoder doesn't have any lifetimes, so it can encode T<'a>.
+                    quote! { #field_name }
                 } else if &static_type != field_type {
                     let underscore_type = replace_lifetimes(field_type, "_");
 
@@ -85,14 +110,43 @@ impl crate::shared::Item for Item {
             Self::CollectInto => quote! {
                 self.#global_field_name.collect_into(out);
             },
+            Self::CollectIntoParallel => quote! {
+                self.#global_field_name.collect_into_parallel(out);
+            },
             Self::Reserve => quote! {
                 self.#global_field_name.reserve(__additional);
             },
+            Self::HeapSize => quote! {
+                + self.#global_field_name.heap_size()
+            },
+            Self::EncodedLen => quote! {
+                + self.#global_field_name.encoded_len()
+            },
+            Self::Clear => quote! {
+                self.#global_field_name.clear();
+            },
+            Self::ShrinkToFit => quote! {
+                self.#global_field_name.shrink_to_fit();
+            },
+            Self::MaxEncodedLen => {
+                let private = &attrs.private;
+                if attrs.serde || attrs.recursive {
+                    return quote! { ::core::option::Option::None, };
+                }
+                if attrs.skip {
+                    return quote! { ::core::option::Option::Some(0), };
+                }
+                let static_type = replace_lifetimes(field_type, "static");
+                quote! {
+                    <#static_type as #private::Encode>::MAX_ENCODED_LEN,
+                }
+            }
         }
     }
 
     fn struct_impl(
         self,
+        attrs: &BitcodeDeriveAttrs,
         ident: &Ident,
         destructure_fields: &TokenStream,
         do_fields: &TokenStream,
@@ -104,6 +158,10 @@ impl crate::shared::Item for Item {
                     #do_fields
                 }
             }
+            Self::MaxEncodedLen => {
+                let private = &attrs.private;
+                quote! { #private::max_len_sum(&[#do_fields]) }
+            }
             _ => quote! { #do_fields },
         }
     }
@@ -203,18 +261,55 @@ impl crate::shared::Item for Item {
                     self.encode(t);
                 }
             },
-            Self::CollectInto => {
-                let variants = encode_variants
-                    .then(|| {
-                        quote! { self.variants.collect_into(out); }
-                    })
-                    .unwrap_or_default();
+            Self::CollectInto
+            | Self::CollectIntoParallel
+            | Self::HeapSize
+            | Self::EncodedLen
+            | Self::Clear
+            | Self::ShrinkToFit => {
+                let variants = if !encode_variants {
+                    quote! {}
+                } else if let Self::CollectInto = self {
+                    quote! { self.variants.collect_into(out); }
+                } else if let Self::CollectIntoParallel = self {
+                    quote! { self.variants.collect_into_parallel(out); }
+                } else if let Self::HeapSize = self {
+                    quote! { + self.variants.heap_size() }
+                } else if let Self::EncodedLen = self {
+                    quote! { + self.variants.encoded_len() }
+                } else if let Self::Clear = self {
+                    quote! { self.variants.clear(); }
+                } else {
+                    quote! { self.variants.shrink_to_fit(); }
+                };
                 let inners: TokenStream = (0..variant_count).map(|i| inner(self, i)).collect();
                 quote! {
                     #variants
                     #inners
                 }
             }
+            Self::MaxEncodedLen => {
+                let private = &attrs.private;
+                let inners: TokenStream = (0..variant_count)
+                    .map(|i| {
+                        let inner = inner(self, i);
+                        quote! { #private::max_len_sum(&[#inner]), }
+                    })
+                    .collect();
+                let inners = quote! { #private::max_len_max(&[#inners]) };
+                if encode_variants {
+                    // u8 variants are packed without a header (see VariantEncoder).
+                    let variants = match variant_index_type {
+                        VariantIndexType::U8 => quote! { ::core::option::Option::Some(1) },
+                        VariantIndexType::U16 => quote! {
+                            <#variant_index_type as #private::Encode>::MAX_ENCODED_LEN
+                        },
+                    };
+                    quote! { #private::max_len_sum(&[#variants, #inners]) }
+                } else {
+                    inners
+                }
+            }
             Self::Reserve => {
                 encode_variants
                     .then(|| {
@@ -246,6 +341,11 @@ impl crate::shared::Derive<{ Item::COUNT }> for Encode {
         None
     }
 
+    fn serde_bound(&self, attrs: &BitcodeDeriveAttrs) -> Path {
+        let private = &attrs.private;
+        parse_quote!(#private::Serialize)
+    }
+
     fn derive_impl(
         &self,
         attrs: &BitcodeDeriveAttrs,
@@ -263,7 +363,7 @@ impl crate::shared::Derive<{ Item::COUNT }> for Encode {
         let (encoder_impl_generics, encoder_generics, encoder_where_clause) =
             generics.split_for_impl();
 
-        let [type_body, default_body, encode_body, encode_vectored_body, collect_into_body, reserve_body] =
+        let [type_body, default_body, encode_body, encode_vectored_body, collect_into_body, collect_into_parallel_body, reserve_body, heap_size_body, encoded_len_body, clear_body, shrink_to_fit_body, max_encoded_len_body] =
             output;
         let encoder_ident = Ident::new(&format!("{ident}Encoder"), Span::call_site());
         let encoder_ty = quote! { #encoder_ident #encoder_generics };
@@ -274,6 +374,7 @@ impl crate::shared::Derive<{ Item::COUNT }> for Encode {
             const _: () = {
                 impl #impl_generics #private::Encode for #input_ty #where_clause {
                     type Encoder = #encoder_ty;
+                    const MAX_ENCODED_LEN: ::core::option::Option<usize> = #max_encoded_len_body;
                 }
 
                 #[allow(non_snake_case)]
@@ -308,13 +409,35 @@ impl crate::shared::Derive<{ Item::COUNT }> for Encode {
                 }
 
                 impl #encoder_impl_generics #private::Buffer for #encoder_ty #encoder_where_clause {
-                    fn collect_into(&mut self, #[allow(unused)] out: &mut #private::Vec<u8>) {
+                    fn collect_into(&mut self, #[allow(unused)] out: &mut #private::Output) {
                         #collect_into_body
                     }
 
                     fn reserve(&mut self, __additional: ::core::num::NonZeroUsize) {
                         #reserve_body
                     }
+
+                    fn heap_size(&self) -> usize {
+                        0 #heap_size_body
+                    }
+
+                    fn encoded_len(&mut self) -> usize {
+                        0 #encoded_len_body
+                    }
+
+                    fn clear(&mut self) {
+                        #clear_body
+                    }
+
+                    fn shrink_to_fit(&mut self) {
+                        #shrink_to_fit_body
+                    }
+
+                    #private::if_rayon! {
+                        fn collect_into_parallel<'__s>(&'__s mut self, #[allow(unused)] out: &mut #private::ParallelOutput<'__s>) {
+                            #collect_into_parallel_body
+                        }
+                    }
                 }
             };
         }
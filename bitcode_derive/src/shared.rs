@@ -79,6 +79,7 @@ pub trait Item: Copy + Sized {
 
     fn struct_impl(
         self,
+        attrs: &BitcodeDeriveAttrs,
         ident: &Ident,
         destructure_fields: &TokenStream,
         do_fields: &TokenStream,
@@ -130,6 +131,14 @@ pub trait Derive<const ITEM_COUNT: usize> {
     /// Bound for skipped fields, e.g. `Default`
     fn skip_bound(&self) -> Option<Path>;
 
+    /// Bound for `#[bitcode(serde)]` fields, e.g. `Serialize`
+    fn serde_bound(&self, attrs: &BitcodeDeriveAttrs) -> Path;
+
+    /// Whether `#[bitcode(project_from)]` is supported.
+    fn supports_project_from(&self) -> bool {
+        false
+    }
+
     /// Generates the derive implementation.
     fn derive_impl(
         &self,
@@ -150,8 +159,26 @@ pub trait Derive<const ITEM_COUNT: usize> {
             .iter()
             .map(move |field| {
                 let field_attrs = BitcodeFieldAttrs::parse(&field.attrs, attrs)?;
-                let bound = if field_attrs.skip {
+                if field_attrs.skip && field_attrs.serde {
+                    return err(field, "can't both skip and serde");
+                }
+                if field_attrs.recursive && (field_attrs.skip || field_attrs.serde) {
+                    return err(field, "can't be recursive and skip or serde");
+                }
+                if attrs.project_from.is_some() && (field_attrs.serde || field_attrs.recursive) {
+                    return err(
+                        field,
+                        "can't be serde or recursive since project_from decodes the original field",
+                    );
+                }
+                let bound = if field_attrs.recursive && field_attrs.bound_type.is_none() {
+                    // Bounding the field type (e.g. Box<Self>: Encode) would be cyclic. Generic
+                    // parameters are bounded by the other fields or #[bitcode(bound_type)].
+                    None
+                } else if field_attrs.skip {
                     self.skip_bound()
+                } else if field_attrs.serde {
+                    Some(self.serde_bound(&attrs))
                 } else {
                     Some(self.bound(&attrs))
                 };
@@ -166,12 +193,20 @@ pub trait Derive<const ITEM_COUNT: usize> {
     fn derive(&self, mut input: DeriveInput) -> Result<TokenStream> {
         let attrs = BitcodeDeriveAttrs::parse(&input.attrs)?;
         let ident = input.ident;
+        if let Some(project_from) = &attrs.project_from {
+            if !self.supports_project_from() {
+                return err(project_from, "project_from only applies to Decode");
+            }
+            if !matches!(input.data, Data::Struct(_)) {
+                return err(&ident, "project_from can only apply to structs");
+            }
+        }
         syn::visit_mut::visit_data_mut(&mut ReplaceSelves(&ident), &mut input.data);
         let mut bounds = FieldBounds::default();
 
         let output = match input.data {
             Data::Struct(DataStruct { ref fields, .. }) => {
-                // Used for adding `bounds` and skipping fields. Would be used by `#[bitcode(with_serde)]`.
+                // Used for adding `bounds`, skipping fields, and `#[bitcode(serde)]`.
                 let field_attrs = self.field_attrs(
                     BitcodeDeriveOrVariantAttrs::Derive(&attrs),
                     fields,
@@ -181,7 +216,7 @@ pub trait Derive<const ITEM_COUNT: usize> {
                 let destructure_fields = &destructure_fields(fields);
                 Self::ALL.map(|item| {
                     let field_impls = item.field_impls(&field_attrs, None, fields);
-                    item.struct_impl(&ident, destructure_fields, &field_impls)
+                    item.struct_impl(&attrs, &ident, destructure_fields, &field_impls)
                 })
             }
             Data::Enum(data_enum) => {
@@ -201,7 +236,7 @@ pub trait Derive<const ITEM_COUNT: usize> {
                     }
                 }
 
-                // Used for adding `bounds` and skipping fields. Would be used by `#[bitcode(with_serde)]`.
+                // Used for adding `bounds`, skipping fields, and `#[bitcode(serde)]`.
                 let variants_with_attrs = data_enum
                     .variants
                     .iter()
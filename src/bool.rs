@@ -1,7 +1,7 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::fast::{CowSlice, NextUnchecked, PushUnchecked, SliceImpl, Unaligned, VecImpl};
-use crate::pack::{pack_bools, unpack_bools};
-use alloc::vec::Vec;
+use crate::output::Output;
+use crate::pack::{pack_bools, pack_bools_len, unpack_bools};
 use core::num::NonZeroUsize;
 
 #[derive(Default)]
@@ -20,7 +20,7 @@ impl Encoder<bool> for BoolEncoder {
 }
 
 impl Buffer for BoolEncoder {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         pack_bools(self.0.as_slice(), out);
         self.0.clear();
     }
@@ -28,16 +28,65 @@ impl Buffer for BoolEncoder {
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional.get());
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        pack_bools_len(self.0.len())
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        let len = self.0.len();
+        out.collect(self, len);
+    }
 }
 
 #[derive(Default)]
 pub struct BoolDecoder<'a>(CowSlice<'a, bool>);
 
+impl<'a> BoolDecoder<'a> {
+    // For validating bools alongside other fields (e.g. BigInt's sign).
+    #[cfg_attr(not(feature = "num-bigint"), allow(unused))]
+    pub(crate) fn borrowed_clone<'me: 'a>(&'me self) -> BoolDecoder<'me> {
+        let mut cow = CowSlice::default();
+        cow.set_borrowed_slice_impl(self.0.ref_slice().clone());
+        Self(cow)
+    }
+}
+
 impl<'a> View<'a> for BoolDecoder<'a> {
     fn populate(&mut self, input: &mut &'_ [u8], length: usize) -> Result<()> {
         unpack_bools(input, length, &mut self.0)?;
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        tasks.populate(self, input, length, crate::pack::skip_bools)
+    }
 }
 
 impl<'a> Decoder<'a, bool> for BoolDecoder<'a> {
@@ -52,6 +101,13 @@ impl<'a> Decoder<'a, bool> for BoolDecoder<'a> {
     fn decode(&mut self) -> bool {
         unsafe { self.0.mut_slice().next_unchecked() }
     }
+
+    const SEEKABLE: bool = true;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.0.mut_slice().seek(n);
+    }
 }
 
 #[cfg(test)]
@@ -61,7 +117,7 @@ mod test {
     fn bench_data() -> Vec<bool> {
         (0..=1000).map(|_| false).collect()
     }
-    crate::bench_encode_decode!(bool_vec: Vec<_>);
+    crate::bench_encode_decode!(bool_vec: Vec<bool>);
 }
 
 #[cfg(test)]
@@ -77,5 +133,5 @@ mod test2 {
             })
             .collect()
     }
-    crate::bench_encode_decode!(bool_vecs: Vec<Vec<_>>);
+    crate::bench_encode_decode!(bool_vecs: Vec<Vec<bool>>);
 }
@@ -1,6 +1,7 @@
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::any::TypeId;
+use core::marker::PhantomData;
 
 /// A buffer for reusing allocations between calls to [`Buffer::encode`] and/or [`Buffer::decode`].
 ///
@@ -18,10 +19,14 @@ use core::any::TypeId;
 /// let decoded: &str = buffer.decode(&encoded).unwrap(); // Won't allocate
 /// assert_eq!(original, decoded);
 /// ```
+///
+/// Each type gets its own encoder and decoder, even if it's encoded the same way as another type
+/// (e.g. `Vec<T>` and `VecDeque<T>`), so their memory can be reported and freed per type.
 #[derive(Default)]
 pub struct Buffer {
     pub(crate) registry: Registry,
     pub(crate) out: Vec<u8>, // Isn't stored in registry because all encoders can share this.
+    memory_limit: Option<usize>,
 }
 
 impl Buffer {
@@ -29,32 +34,189 @@ impl Buffer {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns the number of bytes allocated by the buffer for each type it has encoded or
+    /// decoded.
+    ///
+    /// ```rust
+    /// use bitcode::Buffer;
+    ///
+    /// let mut buffer = Buffer::new();
+    /// buffer.encode(&vec![0u32; 1000]);
+    /// let usage = buffer.memory_usage();
+    /// assert!(usage.types.iter().any(|&(name, _)| name.contains("Vec<u32>")));
+    /// assert!(usage.total() >= 4000);
+    /// ```
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let mut types: Vec<(TypeId, &'static str, usize)> = Vec::new();
+        for (_, entry) in &self.registry.0 {
+            // Types which have been encoded and decoded have 2 entries.
+            let bytes = entry.value.size();
+            if let Some(t) = types.iter_mut().find(|t| t.0 == entry.owner) {
+                t.2 += bytes;
+            } else {
+                types.push((entry.owner, entry.name, bytes));
+            }
+        }
+        MemoryUsage {
+            output: self.out.capacity(),
+            types: types
+                .into_iter()
+                .map(|(_, name, bytes)| (name, bytes))
+                .collect(),
+        }
+    }
+
+    /// Returns [`MemoryUsage::total`] without allocating.
+    fn total_heap_size(&self) -> usize {
+        let types: usize = self.registry.0.iter().map(|(_, e)| e.value.size()).sum();
+        types + self.out.capacity()
+    }
+
+    /// Frees memory until the buffer is using at most `max_bytes` (as reported by
+    /// [`Buffer::memory_usage`]). The largest allocations are freed first, starting with encoders'
+    /// unused capacity and then whole encoders and decoders. They're allocated again the next time
+    /// they're needed.
+    pub fn shrink_to(&mut self, max_bytes: usize) {
+        let mut total = self.total_heap_size();
+        if total <= max_bytes {
+            return;
+        }
+
+        // Shrink the largest coders in place first since they're kept for the next call.
+        let mut largest: Vec<usize> = (0..self.registry.0.len()).collect();
+        largest.sort_unstable_by_key(|&i| core::cmp::Reverse(self.registry.0[i].1.value.size()));
+        for i in largest {
+            if total <= max_bytes {
+                return;
+            }
+            let value = &mut self.registry.0[i].1.value;
+            total -= value.size();
+            value.shrink_to_fit();
+            total += value.size();
+        }
+
+        // Then drop whole coders.
+        let mut sizes: Vec<(usize, Option<TypeId>)> = self
+            .registry
+            .0
+            .iter()
+            .map(|(k, entry)| (entry.value.size(), Some(*k)))
+            .collect();
+        sizes.push((self.out.capacity(), None));
+        sizes.sort_unstable_by_key(|&(bytes, _)| core::cmp::Reverse(bytes));
+
+        for (bytes, key) in sizes {
+            if total <= max_bytes {
+                break;
+            }
+            total -= bytes;
+            if let Some(key) = key {
+                self.registry.0.retain(|(k, _)| *k != key);
+            } else {
+                self.out = Vec::new();
+            }
+        }
+    }
+
+    /// Frees the memory used to encode and decode `T`. It's allocated again the next time `T` is
+    /// encoded or decoded.
+    ///
+    /// ```rust
+    /// use bitcode::Buffer;
+    ///
+    /// let mut buffer = Buffer::new();
+    /// buffer.encode(&vec![0u32; 1000]);
+    /// buffer.clear_type::<Vec<u32>>();
+    /// assert!(buffer.memory_usage().types.is_empty());
+    /// ```
+    pub fn clear_type<T: ?Sized>(&mut self) {
+        let owner = non_static_type_id::<T>();
+        self.registry.0.retain(|(_, entry)| entry.owner != owner);
+    }
+
+    /// Automatically calls [`Buffer::shrink_to(limit)`](Buffer::shrink_to) when the buffer is
+    /// using more than `limit` bytes. It's checked at the start of each call to encode or decode,
+    /// so the memory used by one call is kept until the next. `None` (the default) never frees
+    /// memory.
+    ///
+    /// ```rust
+    /// use bitcode::Buffer;
+    ///
+    /// let mut buffer = Buffer::new();
+    /// buffer.set_memory_limit(Some(1 << 20));
+    /// buffer.encode(&vec![0u64; 1 << 20]); // Spike
+    /// buffer.encode(&0u64); // Frees the spike
+    /// assert!(buffer.memory_usage().total() <= 1 << 20);
+    /// ```
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory_limit = limit;
+    }
+
+    /// Frees memory if the limit from [`Buffer::set_memory_limit`] is exceeded. Called before
+    /// getting a coder out of the registry.
+    pub(crate) fn check_memory_limit(&mut self) {
+        if let Some(limit) = self.memory_limit {
+            if self.total_heap_size() > limit {
+                self.shrink_to(limit);
+            }
+        }
+    }
+}
+
+/// The memory allocated by a [`Buffer`]. Returned by [`Buffer::memory_usage`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The number of bytes allocated for the output of [`Buffer::encode`], which is shared by all
+    /// types.
+    pub output: usize,
+    /// The name of each type (from [`core::any::type_name`]) and the number of bytes allocated to
+    /// encode and decode it.
+    pub types: Vec<(&'static str, usize)>,
+}
+
+impl MemoryUsage {
+    /// Returns the total number of bytes allocated.
+    pub fn total(&self) -> usize {
+        self.output + self.types.iter().map(|&(_, bytes)| bytes).sum::<usize>()
+    }
 }
 
 // Set of arbitrary types.
 #[derive(Default)]
-pub(crate) struct Registry(Vec<(TypeId, ErasedBox)>);
+pub(crate) struct Registry(Vec<(TypeId, Entry)>);
+
+struct Entry {
+    owner: TypeId, // The type which is encoded/decoded, for Buffer::clear_type.
+    name: &'static str,
+    value: ErasedBox,
+}
 
 impl Registry {
     /// Gets a `&mut T` if it already exists or initializes one with [`Default`].
     #[cfg(test)]
     pub(crate) fn get<T: Default + Send + Sync + 'static>(&mut self) -> &mut T {
         // Safety: T is static.
-        unsafe { self.get_non_static::<T>() }
+        unsafe { self.get_non_static::<T, T>(|_| 0, |_| {}) }
     }
 
-    /// Like [`Registry::get`] but can get non-static types.
+    /// Like [`Registry::get`] but can get non-static types. Gets a `C` for each owner `T` (e.g.
+    /// `T::Encoder` for `T`), so [`Buffer::clear_type::<T>`] can find it.
     /// # Safety
     /// Lifetimes are the responsibility of the caller. `&'static [u8]` and `&'a [u8]` are the same
     /// type from the perspective of this function.
-    pub(crate) unsafe fn get_non_static<T: Default + Send + Sync>(&mut self) -> &mut T {
+    pub(crate) unsafe fn get_non_static<T: ?Sized, C: Default + Send + Sync>(
+        &mut self,
+        heap_size: fn(&C) -> usize,
+        shrink_to_fit: fn(&mut C),
+    ) -> &mut C {
         // Use non-generic function to avoid monomorphization.
         #[inline(never)]
-        fn inner(me: &mut Registry, type_id: TypeId, create: fn() -> ErasedBox) -> *mut () {
+        fn inner(me: &mut Registry, type_id: TypeId, create: &dyn Fn() -> Entry) -> *mut () {
             // Use sorted Vec + binary search because we expect fewer insertions than lookups.
             // We could use a HashMap, but that seems like overkill.
             match me.0.binary_search_by_key(&type_id, |(k, _)| *k) {
-                Ok(i) => me.0[i].1.ptr,
+                Ok(i) => me.0[i].1.value.ptr,
                 Err(i) => {
                     #[cold]
                     #[inline(never)]
@@ -62,22 +224,26 @@ impl Registry {
                         me: &mut Registry,
                         i: usize,
                         type_id: TypeId,
-                        create: fn() -> ErasedBox,
+                        create: &dyn Fn() -> Entry,
                     ) -> *mut () {
                         me.0.insert(i, (type_id, create()));
-                        me.0[i].1.ptr
+                        me.0[i].1.value.ptr
                     }
                     cold(me, i, type_id, create)
                 }
             }
         }
-        let erased_ptr = inner(self, non_static_type_id::<T>(), || {
-            // Safety: Caller upholds any lifetime requirements.
-            ErasedBox::new(T::default())
+        let erased_ptr = inner(self, non_static_type_id::<(PhantomData<T>, C)>(), &|| {
+            Entry {
+                owner: non_static_type_id::<T>(),
+                name: core::any::type_name::<T>(),
+                // Safety: Caller upholds any lifetime requirements.
+                value: ErasedBox::new(C::default(), heap_size, shrink_to_fit),
+            }
         });
 
-        // Safety: type_id uniquely identifies the type, so the entry with equal TypeId is a T.
-        &mut *(erased_ptr as *mut T)
+        // Safety: type_id uniquely identifies the type, so the entry with equal TypeId is a C.
+        &mut *(erased_ptr as *mut C)
     }
 }
 
@@ -106,8 +272,11 @@ fn non_static_type_id<T: ?Sized>() -> TypeId {
 
 /// `Box<T>` but of an unknown runtime `T`, requires unsafe to get the `T` back out.
 struct ErasedBox {
-    ptr: *mut (),             // Box<T>
-    drop: unsafe fn(*mut ()), // fn(Box<T>)
+    ptr: *mut (),                           // Box<T>
+    drop: unsafe fn(*mut ()),               // fn(Box<T>)
+    heap_size: unsafe fn(*mut ()) -> usize, // fn(&T) -> usize
+    shrink_to_fit: unsafe fn(*mut ()),      // fn(&mut T)
+    size: usize,                            // size_of::<T>()
 }
 
 // Safety: `ErasedBox::new` ensures `T: Send + Sync`.
@@ -118,10 +287,36 @@ impl ErasedBox {
     /// Allocates a [`Box<T>`] which doesn't know its own type. Only works on `T: Sized`.
     /// # Safety
     /// Ignores lifetimes so drop may be called after `T`'s lifetime has expired.
-    unsafe fn new<T: Send + Sync>(t: T) -> Self {
+    unsafe fn new<T: Send + Sync>(
+        t: T,
+        heap_size: fn(&T) -> usize,
+        shrink_to_fit: fn(&mut T),
+    ) -> Self {
         let ptr = Box::into_raw(Box::new(t)) as *mut ();
         let drop: unsafe fn(*mut ()) = core::mem::transmute(drop::<Box<T>> as fn(Box<T>));
-        Self { ptr, drop }
+        let heap_size: unsafe fn(*mut ()) -> usize = core::mem::transmute(heap_size);
+        let shrink_to_fit: unsafe fn(*mut ()) = core::mem::transmute(shrink_to_fit);
+        Self {
+            ptr,
+            drop,
+            heap_size,
+            shrink_to_fit,
+            size: size_of::<T>(),
+        }
+    }
+
+    /// Returns the number of bytes allocated by the box (including the `T` in it).
+    fn size(&self) -> usize {
+        // Safety: `ErasedBox::new` put a `Box<T>` in self.ptr and an `fn(&T) -> usize` in
+        // self.heap_size.
+        self.size + unsafe { (self.heap_size)(self.ptr) }
+    }
+
+    /// Frees the unused capacity of the `T` in the box.
+    fn shrink_to_fit(&mut self) {
+        // Safety: `ErasedBox::new` put a `Box<T>` in self.ptr and an `fn(&mut T)` in
+        // self.shrink_to_fit.
+        unsafe { (self.shrink_to_fit)(self.ptr) };
     }
 }
 
@@ -134,7 +329,7 @@ impl Drop for ErasedBox {
 
 #[cfg(test)]
 mod tests {
-    use super::{non_static_type_id, Buffer, ErasedBox, Registry};
+    use super::{non_static_type_id, Buffer, ErasedBox, MemoryUsage, Registry};
     use test::{black_box, Bencher};
 
     #[test]
@@ -149,6 +344,88 @@ mod tests {
         assert_send_sync::<Buffer>()
     }
 
+    #[test]
+    fn memory_usage() {
+        use crate::{Decode, Encode};
+        use alloc::string::String;
+        use alloc::vec::Vec;
+
+        #[derive(Encode, Decode)]
+        enum Message {
+            Text(String),
+            Numbers(Vec<u64>),
+        }
+        fn bytes_of<T: ?Sized>(b: &Buffer) -> usize {
+            let usage = b.memory_usage();
+            let name = core::any::type_name::<T>();
+            usage
+                .types
+                .iter()
+                .filter(|t| t.0 == name)
+                .map(|t| t.1)
+                .sum()
+        }
+
+        let mut b = Buffer::new();
+        assert_eq!(b.memory_usage(), MemoryUsage::default());
+        let numbers = Message::Numbers((0..1000).collect());
+        b.encode(&numbers);
+        assert!(bytes_of::<Message>(&b) >= 8000);
+        assert!(b.memory_usage().output > 0);
+
+        // Encoding and decoding the same type is reported together.
+        let bytes = crate::encode(&numbers);
+        b.decode::<Message>(&bytes).unwrap();
+        b.encode(&0u8);
+        let usage = b.memory_usage();
+        assert_eq!(usage.types.len(), 2);
+        assert_eq!(
+            usage.total(),
+            usage.output + bytes_of::<Message>(&b) + bytes_of::<u8>(&b)
+        );
+
+        b.clear_type::<Message>();
+        assert_eq!(bytes_of::<Message>(&b), 0);
+        assert_ne!(bytes_of::<u8>(&b), 0);
+        b.decode::<Message>(&bytes).unwrap();
+
+        // Encoders are shrunk in place before anything is dropped.
+        b.encode(&numbers);
+        let total = b.memory_usage().total();
+        b.shrink_to(total);
+        assert_eq!(b.memory_usage().total(), total);
+        b.shrink_to(total - 1);
+        assert!(b.memory_usage().total() < total - 8000);
+        assert_eq!(b.memory_usage().types.len(), 2);
+
+        // Then the largest allocations are freed first.
+        let total = b.memory_usage().total();
+        b.shrink_to(total - 1000);
+        assert!(bytes_of::<Message>(&b) < 8000); // Dropped the decoder.
+        assert_ne!(bytes_of::<u8>(&b), 0);
+        b.shrink_to(0);
+        assert_eq!(b.memory_usage(), MemoryUsage::default());
+        assert_eq!(b.encode(&numbers), crate::encode(&numbers));
+    }
+
+    #[test]
+    fn memory_limit() {
+        use alloc::vec::Vec;
+
+        let mut b = Buffer::new();
+        b.set_memory_limit(Some(1000));
+        let spike: Vec<u32> = (0..10000).collect();
+        b.encode(&spike);
+        assert!(b.memory_usage().total() > 1000);
+        assert_eq!(b.encode(&1u8), [1]);
+        assert!(b.memory_usage().total() <= 1000);
+
+        b.set_memory_limit(None);
+        b.encode(&spike);
+        b.encode(&1u8);
+        assert!(b.memory_usage().total() > 1000);
+    }
+
     #[test]
     fn registry() {
         let mut r = Registry::default();
@@ -193,7 +470,7 @@ mod tests {
         use alloc::sync::Arc;
         let rc = Arc::new(());
         struct TestDrop(#[allow(unused)] Arc<()>);
-        let b = unsafe { ErasedBox::new(TestDrop(Arc::clone(&rc))) };
+        let b = unsafe { ErasedBox::new(TestDrop(Arc::clone(&rc)), |_| 0, |_| {}) };
         assert_eq!(Arc::strong_count(&rc), 2);
         drop(b);
         assert_eq!(Arc::strong_count(&rc), 1);
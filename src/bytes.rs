@@ -0,0 +1,192 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::vec::VecEncoder;
+use crate::fast::{NextUnchecked, SliceImpl};
+use crate::length::LengthDecoder;
+use crate::output::Output;
+use crate::pack::{
+    pack_bytes_unpacked_header, pack_bytes_unpacked_header_len, unpack_bytes_borrowed,
+};
+use crate::u8_char::U8Char;
+use core::num::NonZeroUsize;
+
+/// Encodes `&[u8]` without packing so it can be decoded without copying. `[u8]` and `Vec<u8>` may be
+/// packed, in which case their encodings differ.
+#[derive(Default)]
+pub struct BytesEncoder(VecEncoder<U8Char>);
+
+#[inline(always)]
+fn bytes_as_u8_chars(b: &[u8]) -> &[U8Char] {
+    bytemuck::must_cast_slice(b)
+}
+
+impl Buffer for BytesEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.0.lengths.collect_into(out);
+        pack_bytes_unpacked_header(self.0.elements.encoded_len(), out);
+        self.0.elements.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.0.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        let bytes = self.0.elements.encoded_len();
+        self.0.lengths.encoded_len() + pack_bytes_unpacked_header_len(bytes) + bytes
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
+impl Encoder<[u8]> for BytesEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &[u8]) {
+        self.0.encode(bytes_as_u8_chars(t));
+    }
+
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a [u8]> + Clone) {
+        self.0.encode_vectored(i.map(bytes_as_u8_chars));
+    }
+}
+
+// TODO find a way to remove this shim.
+impl<'b> Encoder<&'b [u8]> for BytesEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &&[u8]) {
+        self.encode(*t);
+    }
+
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a &'b [u8]> + Clone)
+    where
+        &'b [u8]: 'a,
+    {
+        self.encode_vectored(i.copied());
+    }
+}
+
+/// Decodes `&[u8]` by borrowing it. Fails if the bytes were packed (e.g. encoded as `[u8]` or
+/// `Vec<u8>`).
+#[derive(Default)]
+pub struct BytesDecoder<'a> {
+    lengths: LengthDecoder<'a>,
+    bytes: SliceImpl<'a, u8>,
+}
+
+impl<'a> View<'a> for BytesDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.lengths.populate(input, length)?;
+        self.bytes = unpack_bytes_borrowed(input, self.lengths.length())?.into();
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size()
+    }
+}
+
+impl<'a> Decoder<'a, &'a [u8]> for BytesDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> &'a [u8] {
+        unsafe { self.bytes.chunk_unchecked(self.lengths.decode()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn zero_copy() {
+        let bytes: &[u8] = &[1, 2, 3, 255];
+        let encoded = encode(&bytes);
+        let decoded = decode::<&[u8]>(&encoded).unwrap();
+        assert_eq!(decoded, bytes);
+        assert!(encoded.as_ptr_range().contains(&decoded.as_ptr()));
+        assert!(decode::<&[u8]>(&encode(&[1u8, 2, 3].as_slice())[..1]).is_err());
+
+        // Small bytes aren't packed either.
+        let bytes: &[u8] = &[0, 1, 0, 1, 1];
+        assert_eq!(decode::<&[u8]>(&encode(&bytes)).unwrap(), bytes);
+        assert_eq!(decode::<Vec<u8>>(&encode(&bytes)).unwrap(), bytes);
+        assert!(decode::<&[u8]>(&encode(&bytes.to_vec())).is_err());
+    }
+
+    #[test]
+    fn packed_asymmetry() {
+        // `[u8]` and `Vec<u8>` pack compressible bytes, but `&[u8]` never does.
+        let slice: &[u8] = &[0; 100];
+        let packed = encode(slice);
+        assert_eq!(packed, encode(&slice.to_vec()));
+        let unpacked = encode(&slice);
+        assert!(packed.len() < unpacked.len());
+
+        // So `&[u8]` can't decode packed bytes, but `Vec<u8>` and `Cow<[u8]>` decode both.
+        assert!(decode::<&[u8]>(&packed).is_err());
+        assert_eq!(decode::<&[u8]>(&unpacked).unwrap(), slice);
+        for encoded in [&packed, &unpacked] {
+            assert_eq!(decode::<Vec<u8>>(encoded).unwrap(), slice);
+            assert_eq!(decode::<Cow<[u8]>>(encoded).unwrap(), slice);
+        }
+    }
+
+    #[test]
+    fn same_layout() {
+        // Every sequence of u8 has the same layout, as long as `Vec<u8>` doesn't pack the bytes.
+        for len in [0, 1, 2, 3, 1000] {
+            let bytes: Vec<u8> = crate::random_data(len);
+            let slice: &[u8] = &bytes;
+            let encoded = encode(&bytes);
+            assert_eq!(encode(&slice), encoded);
+            assert_eq!(encode(slice), encoded);
+            assert_eq!(encode(&Box::<[u8]>::from(slice)), encoded);
+            assert_eq!(encode(&Cow::Borrowed(slice)), encoded);
+
+            assert_eq!(decode::<&[u8]>(&encoded).unwrap(), slice);
+            assert_eq!(decode::<Vec<u8>>(&encode(&slice)).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn cow_bytes() {
+        // Large random bytes can't be packed, so they're borrowed.
+        let bytes: Vec<u8> = crate::random_data(1000);
+        let encoded = encode(&Cow::Borrowed(bytes.as_slice()));
+        assert_eq!(encoded, encode(&bytes)); // Encoded the same way as Vec<u8>.
+        let decoded = decode::<Cow<[u8]>>(&encoded).unwrap();
+        assert!(matches!(decoded, Cow::Borrowed(_)));
+        assert_eq!(decoded, bytes);
+
+        // Small bytes are packed, so they're owned.
+        let bytes = vec![0u8, 1, 0, 1, 1];
+        let encoded = encode(&bytes);
+        let decoded = decode::<Cow<[u8]>>(&encoded).unwrap();
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded, bytes);
+
+        let v: Vec<Cow<[u8]>> = vec![Cow::Borrowed(&[]), Cow::Owned(vec![255, 0]), b"a".into()];
+        assert_eq!(decode::<Vec<Cow<[u8]>>>(&encode(&v)).unwrap(), v);
+    }
+
+    fn bench_data() -> Vec<&'static [u8]> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| &[0xAB; 64][..n as usize / 4])
+            .collect()
+    }
+    crate::bench_encode_decode!(bytes_vec: Vec<&[u8]>);
+}
@@ -1,4 +1,5 @@
 use crate::fast::{SliceImpl, Unaligned, VecImpl};
+use crate::output::Output;
 use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
@@ -10,16 +11,37 @@ pub trait Buffer {
     /// Convenience function for `collect_into`.
     fn collect(&mut self) -> Vec<u8> {
         let mut vec = vec![];
-        self.collect_into(&mut vec);
+        self.collect_into(&mut Output::Vec(&mut vec));
         vec
     }
 
     /// Collects the buffer into a single `Vec<u8>`. This clears the buffer.
-    fn collect_into(&mut self, out: &mut Vec<u8>);
+    fn collect_into(&mut self, out: &mut Output);
 
     /// Reserves space for `additional` calls to `self.encode()`. Takes a [`NonZeroUsize`] to avoid
     /// useless calls.
     fn reserve(&mut self, additional: NonZeroUsize);
+
+    /// Returns the number of bytes allocated on the heap (not including `size_of::<Self>()`).
+    /// Mostly capacity that's saved between calls.
+    fn heap_size(&self) -> usize;
+
+    /// Returns the number of bytes [`Self::collect_into`] would write without writing them. Unlike
+    /// [`Self::collect_into`], this doesn't clear the buffer.
+    fn encoded_len(&mut self) -> usize;
+
+    /// Clears the buffer without collecting it.
+    fn clear(&mut self);
+
+    /// Frees the buffer's unused capacity without dropping it. Buffers are empty between calls to
+    /// [`crate::Buffer::encode`], so this frees almost all of their [`Self::heap_size`].
+    fn shrink_to_fit(&mut self);
+
+    /// Like [`Self::collect_into`], but may defer packing to worker threads.
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        self.collect_into(&mut out.bytes());
+    }
 }
 
 /// Iterators passed to [`Encoder::encode_vectored`] must have length <= this.
@@ -63,6 +85,32 @@ pub trait View<'a> {
     /// Reads `length` items out of `input`, overwriting the view. If it returns `Ok`,
     /// `self.decode()` can be called called `length` times.
     fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()>;
+
+    /// Returns the number of bytes allocated on the heap (not including `size_of::<Self>()`).
+    /// Mostly capacity that's saved between calls.
+    fn heap_size(&self) -> usize;
+
+    /// Sets how many `#[bitcode(recursive)]` fields the view is nested inside. Views containing
+    /// other views must forward it to them so `RecursiveDecoder` can limit the depth.
+    fn set_recursion_depth(&mut self, depth: usize) {
+        let _ = depth;
+    }
+
+    /// Like [`Self::populate`], but may defer unpacking and validation to `tasks`, which must
+    /// finish successfully before decoding.
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        let _ = tasks;
+        self.populate(input, length)
+    }
 }
 
 /// One of [`Decoder::decode`] and [`Decoder::decode_in_place`] must be implemented or calling
@@ -87,7 +135,8 @@ pub trait Decoder<'a, T>: View<'a> + Default + Send + Sync {
     }
 
     /// [`Self::decode`] without redundant copies. Only downside is panics will leak the value.
-    /// The only panics out of our control are Hash/Ord/PartialEq for BinaryHeap/BTreeMap/HashMap.
+    /// The only panics out of our control are Hash/Ord/PartialEq for BinaryHeap/BTreeMap/HashMap
+    /// (including hashbrown and indexmap).
     /// E.g. if a user PartialEq panics we will leak some memory which is an acceptable tradeoff.
     /// # Safety
     /// Can only decode `self.populate(_, length)` items.
@@ -95,6 +144,38 @@ pub trait Decoder<'a, T>: View<'a> + Default + Send + Sync {
     fn decode_in_place(&mut self, out: &mut MaybeUninit<T>) {
         out.write(self.decode());
     }
+
+    /// Like [`Self::decode`], but overwrites `out` in place, reusing its allocations if possible
+    /// (e.g. a `Vec`'s capacity). Only types which allocate need to override it.
+    /// # Safety
+    /// Can only decode `self.populate(_, length)` items.
+    #[inline(always)]
+    fn decode_into(&mut self, out: &mut T) {
+        *out = self.decode();
+    }
+
+    /// If every value decodes the same number of items from each column (e.g. a struct of
+    /// primitives), so the decoder can [`Self::seek`] between values.
+    const SEEKABLE: bool = false;
+
+    /// Moves forwards (or backwards if negative) `n` values without decoding them.
+    /// # Safety
+    /// Requires [`Self::SEEKABLE`]. Must stay within the `self.populate(_, length)` items.
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        let _ = n;
+        unreachable!();
+    }
+
+    /// Decodes `length` values by borrowing them from the input. Only possible for byte-like
+    /// primitives which weren't packed. Returns `None` without decoding anything otherwise.
+    /// # Safety
+    /// Can only decode `self.populate(_, length)` items.
+    #[inline(always)]
+    unsafe fn decode_borrowed(&mut self, length: usize) -> Option<&'a [T]> {
+        let _ = length;
+        None
+    }
 }
 
 #[doc(hidden)]
@@ -108,3 +189,21 @@ macro_rules! __private_uninit_field {
     };
 }
 pub use __private_uninit_field as uninit_field;
+
+/// Expands to its input only with the `"rayon"` feature (for the derive macro).
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __private_if_rayon {
+    ($($t:tt)*) => {
+        $($t)*
+    };
+}
+#[cfg(not(feature = "rayon"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __private_if_rayon {
+    ($($t:tt)*) => {};
+}
+#[cfg(feature = "derive")]
+pub use __private_if_rayon as if_rayon;
@@ -2,7 +2,7 @@ use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::consume::mul_length;
 use crate::derive::{Decode, Encode};
 use crate::fast::{FastSlice, FastVec, Unaligned};
-use alloc::vec::Vec;
+use crate::output::Output;
 use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
 
@@ -43,7 +43,7 @@ impl<T: Encode, const N: usize> Encoder<[T; N]> for ArrayEncoder<T, N> {
 }
 
 impl<T: Encode, const N: usize> Buffer for ArrayEncoder<T, N> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.0.collect_into(out);
     }
 
@@ -57,6 +57,27 @@ impl<T: Encode, const N: usize> Buffer for ArrayEncoder<T, N> {
                 .unwrap(),
         );
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        self.0.collect_into_parallel(out);
+    }
 }
 
 pub struct ArrayDecoder<'a, T: Decode<'a>, const N: usize>(T::Decoder);
@@ -73,6 +94,28 @@ impl<'a, T: Decode<'a>, const N: usize> View<'a> for ArrayDecoder<'a, T, N> {
         let length = mul_length(length, N)?;
         self.0.populate(input, length)
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.0.set_recursion_depth(depth);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        let length = mul_length(length, N)?;
+        self.0.populate_parallel(input, length, tasks)
+    }
 }
 
 impl<'a, T: Decode<'a>, const N: usize> Decoder<'a, [T; N]> for ArrayDecoder<'a, T, N> {
@@ -92,6 +135,21 @@ impl<'a, T: Decode<'a>, const N: usize> Decoder<'a, [T; N]> for ArrayDecoder<'a,
             self.0.decode_in_place(out);
         }
     }
+
+    #[inline(always)]
+    fn decode_into(&mut self, out: &mut [T; N]) {
+        for out in out {
+            self.0.decode_into(out);
+        }
+    }
+
+    const SEEKABLE: bool = <T::Decoder as Decoder<'a, T>>::SEEKABLE;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        // Can only wrap if T is zero sized, in which case seeking does nothing.
+        self.0.seek(n.wrapping_mul(N as isize));
+    }
 }
 
 #[cfg(test)]
@@ -39,6 +39,7 @@ macro_rules! atomic_impl {
         #[cfg(target_has_atomic = $size)]
         impl crate::derive::Encode for $atomic {
             type Encoder = crate::derive::convert::ConvertIntoEncoder<$repr>;
+            const MAX_ENCODED_LEN: Option<usize> = <$repr as crate::derive::Encode>::MAX_ENCODED_LEN;
         }
         #[cfg(target_has_atomic = $size)]
         impl<'a> crate::derive::Decode<'a> for $atomic {
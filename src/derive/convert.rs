@@ -1,5 +1,8 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::derive::{Decode, Encode};
+use crate::fast::vec_heap_size;
+use crate::output::Output;
+use alloc::vec::Vec;
 use core::num::NonZeroUsize;
 
 #[allow(unused)]
@@ -10,6 +13,8 @@ macro_rules! impl_convert {
     ($want: path, $have_encode: ty, $have_decode: ty) => {
         impl crate::derive::Encode for $want {
             type Encoder = crate::derive::convert::ConvertIntoEncoder<$have_encode>;
+            const MAX_ENCODED_LEN: Option<usize> =
+                <$have_encode as crate::derive::Encode>::MAX_ENCODED_LEN;
         }
         impl<'a> crate::derive::Decode<'a> for $want {
             type Decoder = crate::derive::convert::ConvertFromDecoder<'a, $have_decode>;
@@ -20,11 +25,38 @@ macro_rules! impl_convert {
 #[allow(unused)]
 pub(crate) use impl_convert;
 
+/// Like [`impl_convert`] but decoding can fail (see [`TryConvertFrom`]).
+#[allow(unused)]
+macro_rules! impl_try_convert {
+    ($want: path, $have: ty) => {
+        impl_try_convert!($want, $have, $have);
+    };
+    ($want: path, $have_encode: ty, $have_decode: ty) => {
+        impl crate::derive::Encode for $want {
+            type Encoder = crate::derive::convert::ConvertIntoEncoder<$have_encode>;
+            const MAX_ENCODED_LEN: Option<usize> =
+                <$have_encode as crate::derive::Encode>::MAX_ENCODED_LEN;
+        }
+        impl<'a> crate::derive::Decode<'a> for $want {
+            type Decoder = crate::derive::convert::TryConvertFromDecoder<'a, $have_decode, $want>;
+        }
+    };
+}
+
+#[allow(unused)]
+pub(crate) use impl_try_convert;
+
 // Like [`From`] but we can implement it ourselves.
 pub(crate) trait ConvertFrom<T>: Sized {
     fn convert_from(value: T) -> Self;
 }
 
+// Like [`TryFrom`] but we can implement it ourselves. Errors are returned from `populate`.
+#[allow(unused)]
+pub(crate) trait TryConvertFrom<T>: Sized {
+    fn try_convert_from(value: T) -> Result<Self>;
+}
+
 pub struct ConvertIntoEncoder<T: Encode>(T::Encoder);
 
 // Can't derive since it would bound T: Default.
@@ -42,12 +74,28 @@ impl<D, T: Encode + for<'a> ConvertFrom<&'a D>> Encoder<D> for ConvertIntoEncode
 }
 
 impl<T: Encode> Buffer for ConvertIntoEncoder<T> {
-    fn collect_into(&mut self, out: &mut alloc::vec::Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.0.collect_into(out);
     }
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional);
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
 }
 
 /// Decodes a `T` and then converts it with [`ConvertFrom`].
@@ -64,6 +112,14 @@ impl<'a, T: Decode<'a>> View<'a> for ConvertFromDecoder<'a, T> {
     fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
         self.0.populate(input, length)
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.0.set_recursion_depth(depth);
+    }
 }
 
 impl<'a, F: ConvertFrom<T>, T: Decode<'a>> Decoder<'a, F> for ConvertFromDecoder<'a, T> {
@@ -71,4 +127,66 @@ impl<'a, F: ConvertFrom<T>, T: Decode<'a>> Decoder<'a, F> for ConvertFromDecoder
     fn decode(&mut self) -> F {
         F::convert_from(self.0.decode())
     }
+
+    const SEEKABLE: bool = <T::Decoder as Decoder<'a, T>>::SEEKABLE;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.0.seek(n);
+    }
+}
+
+/// Decodes a `T` and then converts it with [`TryConvertFrom`]. Converts them all in
+/// [`View::populate`] so it can return an error.
+#[allow(unused)]
+pub struct TryConvertFromDecoder<'a, T: Decode<'a>, F> {
+    decoder: T::Decoder,
+    values: Vec<F>,
+    index: usize,
+}
+
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>, F> Default for TryConvertFromDecoder<'a, T, F> {
+    fn default() -> Self {
+        Self {
+            decoder: Default::default(),
+            values: Default::default(),
+            index: 0,
+        }
+    }
+}
+
+impl<'a, T: Decode<'a>, F: TryConvertFrom<T>> View<'a> for TryConvertFromDecoder<'a, T, F> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.decoder.populate(input, length)?;
+        self.values.clear();
+        self.index = 0;
+        self.values.reserve(length);
+        for _ in 0..length {
+            self.values
+                .push(F::try_convert_from(self.decoder.decode())?);
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.decoder.heap_size() + vec_heap_size(&self.values)
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.decoder.set_recursion_depth(depth);
+    }
+}
+
+impl<'a, T: Decode<'a>, F: TryConvertFrom<T> + Copy + Send + Sync> Decoder<'a, F>
+    for TryConvertFromDecoder<'a, T, F>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> F {
+        debug_assert!(self.index < self.values.len());
+        // Safety: populate pushed `length` values.
+        let value = unsafe { *self.values.get_unchecked(self.index) };
+        self.index += 1;
+        value
+    }
 }
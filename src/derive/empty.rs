@@ -1,5 +1,5 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
-use alloc::vec::Vec;
+use crate::output::Output;
 use core::marker::PhantomData;
 use core::num::NonZeroUsize;
 
@@ -11,20 +11,39 @@ impl<T> Encoder<PhantomData<T>> for EmptyCoder {
 }
 
 impl Buffer for EmptyCoder {
-    fn collect_into(&mut self, _: &mut Vec<u8>) {}
+    fn collect_into(&mut self, _: &mut Output) {}
     fn reserve(&mut self, _: NonZeroUsize) {}
+    fn heap_size(&self) -> usize {
+        0
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        0
+    }
+
+    fn clear(&mut self) {}
+
+    fn shrink_to_fit(&mut self) {}
 }
 
 impl<'a> View<'a> for EmptyCoder {
     fn populate(&mut self, _: &mut &'a [u8], _: usize) -> Result<()> {
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl<'a, T> Decoder<'a, PhantomData<T>> for EmptyCoder {
     fn decode(&mut self) -> PhantomData<T> {
         PhantomData
     }
+
+    const SEEKABLE: bool = true;
+
+    unsafe fn seek(&mut self, _: isize) {}
 }
 
 #[cfg(test)]
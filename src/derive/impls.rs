@@ -1,8 +1,10 @@
 use crate::bool::{BoolDecoder, BoolEncoder};
+use crate::bytes::{BytesDecoder, BytesEncoder};
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::derive::array::{ArrayDecoder, ArrayEncoder};
 use crate::derive::empty::EmptyCoder;
 use crate::derive::map::{MapDecoder, MapEncoder};
+use crate::derive::max_len;
 use crate::derive::option::{OptionDecoder, OptionEncoder};
 use crate::derive::result::{ResultDecoder, ResultEncoder};
 use crate::derive::smart_ptr::{DerefEncoder, FromDecoder};
@@ -10,7 +12,11 @@ use crate::derive::vec::{VecDecoder, VecEncoder};
 use crate::derive::{Decode, Encode};
 use crate::f32::{F32Decoder, F32Encoder};
 use crate::int::{CheckedIntDecoder, IntDecoder, IntEncoder};
+use crate::output::Output;
+use crate::pack_ints::Int;
 use crate::str::{StrDecoder, StrEncoder};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -19,24 +25,26 @@ use core::mem::MaybeUninit;
 use core::num::*;
 
 macro_rules! impl_both {
-    ($t:ty, $encoder:ident, $decoder:ident) => {
+    ($t:ty, $encoder:ident, $decoder:ident, $max_len:expr) => {
         impl Encode for $t {
             type Encoder = $encoder;
+            const MAX_ENCODED_LEN: Option<usize> = $max_len;
         }
         impl<'a> Decode<'a> for $t {
             type Decoder = $decoder<'a>;
         }
     };
 }
-impl_both!(bool, BoolEncoder, BoolDecoder);
-impl_both!(f32, F32Encoder, F32Decoder);
-impl_both!(String, StrEncoder, StrDecoder);
+impl_both!(bool, BoolEncoder, BoolDecoder, Some(1)); // 8 bools per byte.
+impl_both!(f32, F32Encoder, F32Decoder, Some(4)); // Never packed.
+impl_both!(String, StrEncoder, StrDecoder, None);
 
 macro_rules! impl_int {
     ($($t:ty),+) => {
         $(
             impl Encode for $t {
                 type Encoder = IntEncoder<$t>;
+                const MAX_ENCODED_LEN: Option<usize> = max_len::int(core::mem::size_of::<<$t as Int>::Int>());
             }
             impl<'a> Decode<'a> for $t {
                 type Decoder = IntDecoder<'a, $t>;
@@ -49,6 +57,7 @@ impl_int!(i8, i16, i32, i64, i128, isize);
 // TODO F64Encoder (once F32Encoder is sufficiently optimized).
 impl Encode for f64 {
     type Encoder = IntEncoder<u64>;
+    const MAX_ENCODED_LEN: Option<usize> = u64::MAX_ENCODED_LEN;
 }
 impl<'a> Decode<'a> for f64 {
     type Decoder = IntDecoder<'a, u64>;
@@ -59,6 +68,7 @@ macro_rules! impl_checked_int {
         $(
             impl Encode for $a {
                 type Encoder = IntEncoder<$b>;
+                const MAX_ENCODED_LEN: Option<usize> = <$b>::MAX_ENCODED_LEN;
             }
             impl<'a> Decode<'a> for $a {
                 type Decoder = CheckedIntDecoder<'a, $a, $b>;
@@ -71,48 +81,66 @@ impl_checked_int!(NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZero
 impl_checked_int!(char => u32);
 
 macro_rules! impl_t {
-    ($t:ident, $encoder:ident, $decoder:ident) => {
+    ($t:ident, $encoder:ident, $decoder:ident, $max_len:expr) => {
         impl<T: Encode> Encode for $t<T> {
             type Encoder = $encoder<T>;
+            const MAX_ENCODED_LEN: Option<usize> = $max_len;
         }
         impl<'a, T: Decode<'a>> Decode<'a> for $t<T> {
             type Decoder = $decoder<'a, T>;
         }
     };
 }
-impl_t!(LinkedList, VecEncoder, VecDecoder);
-impl_t!(Option, OptionEncoder, OptionDecoder);
-impl_t!(Vec, VecEncoder, VecDecoder);
-impl_t!(VecDeque, VecEncoder, VecDecoder);
+impl_t!(LinkedList, VecEncoder, VecDecoder, None);
+impl_t!(
+    Option,
+    OptionEncoder,
+    OptionDecoder,
+    max_len::sum(&[Some(1), T::MAX_ENCODED_LEN])
+);
+impl_t!(Vec, VecEncoder, VecDecoder, None);
+impl_t!(VecDeque, VecEncoder, VecDecoder, None);
 
 macro_rules! impl_smart_ptr {
     ($(::$ptr: ident)*) => {
         impl<T: Encode + ?Sized> Encode for $(::$ptr)*<T> {
             type Encoder = DerefEncoder<T>;
+            const MAX_ENCODED_LEN: Option<usize> = T::MAX_ENCODED_LEN;
         }
 
         impl<'a, T: Decode<'a>> Decode<'a> for $(::$ptr)*<T> {
             type Decoder = FromDecoder<'a, T>;
         }
+    };
+    ($(::$ptr: ident)*, shared) => {
+        impl_smart_ptr!($(::$ptr)*);
 
+        // Decode directly into the shared allocation (see vec.rs and str.rs).
         impl<'a, T: Decode<'a>> Decode<'a> for $(::$ptr)*<[T]> {
-            // TODO avoid Vec<T> allocation for Rc<[T]> and Arc<[T]>.
-            type Decoder = FromDecoder<'a, Vec<T>>;
+            type Decoder = VecDecoder<'a, T>;
         }
 
         impl<'a> Decode<'a> for $(::$ptr)*<str> {
-            // TODO avoid String allocation for Rc<str> and Arc<str>.
-            type Decoder = FromDecoder<'a, String>;
+            type Decoder = StrDecoder<'a>;
         }
-    }
+    };
 }
 impl_smart_ptr!(::alloc::boxed::Box);
-impl_smart_ptr!(::alloc::rc::Rc);
+impl_smart_ptr!(::alloc::rc::Rc, shared);
 #[cfg(target_has_atomic = "ptr")]
-impl_smart_ptr!(::alloc::sync::Arc);
+impl_smart_ptr!(::alloc::sync::Arc, shared);
+
+// Vec<T> -> Box<[T]> and String -> Box<str> don't reallocate since their capacity is exact.
+impl<'a, T: Decode<'a>> Decode<'a> for Box<[T]> {
+    type Decoder = FromDecoder<'a, Vec<T>>;
+}
+impl<'a> Decode<'a> for Box<str> {
+    type Decoder = FromDecoder<'a, String>;
+}
 
 impl<T: Encode, const N: usize> Encode for [T; N] {
     type Encoder = ArrayEncoder<T, N>;
+    const MAX_ENCODED_LEN: Option<usize> = max_len::mul(T::MAX_ENCODED_LEN, N);
 }
 impl<'a, T: Decode<'a>, const N: usize> Decode<'a> for [T; N] {
     type Decoder = ArrayDecoder<'a, T, N>;
@@ -134,6 +162,32 @@ impl<'a> Decode<'a> for &'a str {
     type Decoder = StrDecoder<'a>;
 }
 
+// Zero copy deserialization of bytes. Unlike [u8] and Vec<u8>, &[u8] is never packed so it can be
+// borrowed. This makes the formats differ: encode(slice) may pack but encode(&slice) never does, and
+// decoding &[u8] fails on packed bytes (decode Vec<u8> or Cow<[u8]> to accept both).
+impl Encode for &[u8] {
+    type Encoder = BytesEncoder;
+}
+impl<'a> Decode<'a> for &'a [u8] {
+    type Decoder = BytesDecoder<'a>;
+}
+
+// Encoded like String, always borrows.
+impl Encode for Cow<'_, str> {
+    type Encoder = StrEncoder;
+}
+impl<'a> Decode<'a> for Cow<'a, str> {
+    type Decoder = StrDecoder<'a>;
+}
+
+// Encoded like Vec<T>, borrows if T is a byte-like primitive (e.g. u8) which wasn't packed.
+impl<T: Encode + Clone> Encode for Cow<'_, [T]> {
+    type Encoder = VecEncoder<T>;
+}
+impl<'a, T: Decode<'a> + Clone> Decode<'a> for Cow<'a, [T]> {
+    type Decoder = VecDecoder<'a, T>;
+}
+
 impl<T: Encode> Encode for BinaryHeap<T> {
     type Encoder = VecEncoder<T>;
 }
@@ -156,6 +210,10 @@ impl<'a, K: Decode<'a> + Ord, V: Decode<'a>> Decode<'a> for BTreeMap<K, V> {
 
 impl<T: Encode, E: Encode> Encode for core::result::Result<T, E> {
     type Encoder = ResultEncoder<T, E>;
+    const MAX_ENCODED_LEN: Option<usize> = max_len::sum(&[
+        Some(1),
+        max_len::max(&[T::MAX_ENCODED_LEN, E::MAX_ENCODED_LEN]),
+    ]);
 }
 impl<'a, T: Decode<'a>, E: Decode<'a>> Decode<'a> for core::result::Result<T, E> {
     type Decoder = ResultDecoder<'a, T, E>;
@@ -208,6 +266,7 @@ mod with_std {
 
 impl<T> Encode for PhantomData<T> {
     type Encoder = EmptyCoder;
+    const MAX_ENCODED_LEN: Option<usize> = Some(0);
 }
 impl<'a, T> Decode<'a> for PhantomData<T> {
     type Decoder = EmptyCoder;
@@ -220,6 +279,7 @@ macro_rules! impl_tuples {
             const _: () = {
                 impl<$($name: Encode,)*> Encode for ($($name,)*) {
                     type Encoder = TupleEncoder<$($name,)*>;
+                    const MAX_ENCODED_LEN: Option<usize> = max_len::sum(&[$($name::MAX_ENCODED_LEN,)*]);
                 }
 
                 pub struct TupleEncoder<$($name: Encode,)*>(
@@ -251,7 +311,7 @@ macro_rules! impl_tuples {
                 }
 
                 impl<$($name: Encode,)*> Buffer for TupleEncoder<$($name,)*> {
-                    fn collect_into(&mut self, out: &mut Vec<u8>) {
+                    fn collect_into(&mut self, out: &mut Output) {
                         $(
                             self.$n.collect_into(out);
                         )*
@@ -262,6 +322,33 @@ macro_rules! impl_tuples {
                             self.$n.reserve(length);
                         )*
                     }
+
+                    fn heap_size(&self) -> usize {
+                        0 $(+ self.$n.heap_size())*
+                    }
+
+                    fn encoded_len(&mut self) -> usize {
+                        0 $(+ self.$n.encoded_len())*
+                    }
+
+                    fn clear(&mut self) {
+                        $(
+                            self.$n.clear();
+                        )*
+                    }
+
+                    fn shrink_to_fit(&mut self) {
+                        $(
+                            self.$n.shrink_to_fit();
+                        )*
+                    }
+
+                    #[cfg(feature = "rayon")]
+                    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+                        $(
+                            self.$n.collect_into_parallel(out);
+                        )*
+                    }
                 }
 
                 impl<'a, $($name: Decode<'a>,)*> Decode<'a> for ($($name,)*) {
@@ -289,6 +376,22 @@ macro_rules! impl_tuples {
                             self.$n.decode_in_place(crate::coder::uninit_field!(out.$n: $name));
                         )*
                     }
+
+                    #[inline(always)]
+                    fn decode_into(&mut self, out: &mut ($($name,)*)) {
+                        $(
+                            self.$n.decode_into(&mut out.$n);
+                        )*
+                    }
+
+                    const SEEKABLE: bool = true $(&& <$name::Decoder as Decoder<'a, $name>>::SEEKABLE)*;
+
+                    #[inline(always)]
+                    unsafe fn seek(&mut self, n: isize) {
+                        $(
+                            self.$n.seek(n);
+                        )*
+                    }
                 }
 
                 impl<'a, $($name: Decode<'a>,)*> View<'a> for TupleDecoder<'a, $($name,)*> {
@@ -298,6 +401,32 @@ macro_rules! impl_tuples {
                         )*
                         Ok(())
                     }
+
+                    fn heap_size(&self) -> usize {
+                        0 $(+ self.$n.heap_size())*
+                    }
+
+                    fn set_recursion_depth(&mut self, depth: usize) {
+                        $(
+                            self.$n.set_recursion_depth(depth);
+                        )*
+                    }
+
+                    #[cfg(feature = "rayon")]
+                    fn populate_parallel<'s>(
+                        &'s mut self,
+                        input: &mut &'a [u8],
+                        length: usize,
+                        tasks: &mut crate::parallel::ParallelInput<'s>,
+                    ) -> Result<()>
+                    where
+                        'a: 's,
+                    {
+                        $(
+                            self.$n.populate_parallel(input, length, tasks)?;
+                        )*
+                        Ok(())
+                    }
                 }
             };
         )+
@@ -0,0 +1,134 @@
+use crate::coder::{Decoder, Result, View};
+use crate::derive::wrapper::{Wrapper, WrapperEncoder};
+use crate::derive::{Decode, Encode};
+use crate::str::StrDecoder;
+use core::ops::Deref;
+use std::collections::HashMap;
+
+/// Deduplicates identical strings such as `Arc<str>` when decoding. Encoded the same way as `T`.
+///
+/// All equal strings decoded from a single message share the same allocation. This is opt-in
+/// since it's slower than decoding `T` when strings are rarely repeated.
+///
+/// ```rust
+/// use bitcode::{Decode, Encode, Interned};
+/// use std::sync::Arc;
+///
+/// #[derive(Encode, Decode)]
+/// struct Entity {
+///     name: Interned<Arc<str>>,
+/// }
+///
+/// let name: Arc<str> = "goblin".into();
+/// let entities: Vec<_> = (0..2).map(|_| Entity { name: Interned(name.clone()) }).collect();
+/// let decoded: Vec<Entity> = bitcode::decode(&bitcode::encode(&entities)).unwrap();
+/// assert!(Arc::ptr_eq(&decoded[0].name.0, &decoded[1].name.0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interned<T>(pub T);
+
+impl<T> Deref for Interned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Deref<Target = str>> Wrapper<str> for Interned<T> {
+    #[inline(always)]
+    fn with_inner<R>(&self, f: impl FnOnce(&str) -> R) -> R {
+        f(&self.0)
+    }
+}
+
+impl<T: Deref<Target = str>> Encode for Interned<T> {
+    type Encoder = WrapperEncoder<str>;
+}
+impl<'a, T: From<&'a str> + Clone + Send + Sync> Decode<'a> for Interned<T> {
+    type Decoder = InternedDecoder<'a, T>;
+}
+
+pub struct InternedDecoder<'a, T> {
+    strings: StrDecoder<'a>,
+    // Cleared after the last value is decoded, so values are only shared within a single message
+    // and aren't kept alive by the decoder.
+    interned: HashMap<&'a str, T>,
+    remaining: usize,
+}
+
+// Can't derive since it would bound T: Default.
+impl<T> Default for InternedDecoder<'_, T> {
+    fn default() -> Self {
+        Self {
+            strings: Default::default(),
+            interned: Default::default(),
+            remaining: 0,
+        }
+    }
+}
+
+impl<'a, T> View<'a> for InternedDecoder<'a, T> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.interned.clear();
+        self.remaining = length;
+        self.strings.populate(input, length)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.strings.heap_size() + self.interned.capacity() * size_of::<(&str, T)>()
+    }
+}
+
+impl<'a, T: From<&'a str> + Clone + Send + Sync> Decoder<'a, Interned<T>>
+    for InternedDecoder<'a, T>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> Interned<T> {
+        let s: &'a str = self.strings.decode();
+        let t = self.interned.entry(s).or_insert_with(|| s.into()).clone();
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.interned.clear();
+        }
+        Interned(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interned;
+    use crate::{encode, Buffer};
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn interned() {
+        let v: Vec<Arc<str>> = ["a", "b", "a", "", "b", "a"].map(Arc::from).into();
+        let encoded = encode(&v);
+        let interned: Vec<Interned<Arc<str>>> = v.iter().cloned().map(Interned).collect();
+        assert_eq!(encode(&interned), encoded);
+
+        let mut buffer = Buffer::new();
+        for _ in 0..2 {
+            let decoded = buffer.decode::<Vec<Interned<Arc<str>>>>(&encoded).unwrap();
+            assert_eq!(decoded, interned);
+            assert!(Arc::ptr_eq(&decoded[0], &decoded[2]));
+            assert!(Arc::ptr_eq(&decoded[0], &decoded[5]));
+            assert!(Arc::ptr_eq(&decoded[1], &decoded[4]));
+            assert!(!Arc::ptr_eq(&decoded[0], &decoded[1]));
+
+            // The decoder doesn't keep the values alive.
+            assert_eq!(Arc::strong_count(&decoded[0]), 3);
+        }
+    }
+
+    fn bench_data() -> Vec<Interned<Arc<str>>> {
+        let names: Vec<Arc<str>> = (0..16).map(|i| alloc::format!("name{i}").into()).collect();
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|i| Interned(names[i as usize % names.len()].clone()))
+            .collect()
+    }
+    crate::bench_encode_decode!(interned_vec: Vec<_>);
+}
@@ -0,0 +1,182 @@
+use crate::coder::{Buffer, Decoder, Encoder, View};
+use crate::consume::expect_eof;
+use crate::derive::vec::{encode_elements_chunked, VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use crate::Error;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::num::NonZeroUsize;
+
+/// Encodes the elements of an [`ExactSizeIterator`] into the same bytes as encoding a `Vec<T>` of
+/// them, without collecting them into a `Vec<T>`.
+///
+/// ```
+/// let v = vec![(1u8, "a"), (2, "b"), (3, "c")];
+/// let bytes = bitcode::encode_iter(v.iter().rev());
+/// let reversed: Vec<_> = v.iter().rev().copied().collect();
+/// assert_eq!(bytes, bitcode::encode(&reversed));
+/// ```
+///
+/// # Panics
+///
+/// If the iterator yields fewer items than its [`len`](ExactSizeIterator::len). Items past its
+/// `len` are ignored.
+///
+/// **Warning:** The format is subject to change between major versions.
+pub fn encode_iter<'a, T: Encode + 'a, I: IntoIterator<Item = &'a T>>(i: I) -> Vec<u8>
+where
+    I::IntoIter: ExactSizeIterator,
+{
+    let i = i.into_iter();
+    let n = i.len();
+    // encode_vectored needs a Clone iterator, so the references are collected.
+    let elements: Vec<&T> = i.take(n).collect();
+    // Otherwise the encoded length would be wrong.
+    assert_eq!(elements.len(), n, "iterator shorter than its len");
+
+    let mut encoder = VecEncoder::<T>::default();
+    encoder.reserve(NonZeroUsize::new(1).unwrap());
+    encoder.lengths.encode(&n);
+    encode_elements_chunked::<T, _>(&mut encoder.elements, &elements);
+    encoder.collect()
+}
+
+/// Validates `bytes` encoded from a `Vec<T>` (or any other sequence of `T` encoded the same way)
+/// like [`decode`](crate::decode), but returns an iterator which decodes one element at a time
+/// instead of allocating the whole `Vec<T>`.
+///
+/// ```
+/// let bytes = bitcode::encode(&vec![1u32, 2, 3]);
+/// let sum: u32 = bitcode::decode_iter::<u32>(&bytes).unwrap().sum();
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// **Warning:** The format is subject to change between major versions.
+pub fn decode_iter<'a, T: Decode<'a>>(mut bytes: &'a [u8]) -> Result<DecodeIter<'a, T>, Error> {
+    let mut decoder = VecDecoder::<'a, T>::default();
+    decoder.populate(&mut bytes, 1)?;
+    expect_eof(bytes)?;
+    Ok(DecodeIter {
+        remaining: decoder.lengths.decode(),
+        decoder: decoder.elements,
+    })
+}
+
+/// An iterator which decodes the elements of a `Vec<T>`. Returned by [`decode_iter`].
+pub struct DecodeIter<'a, T: Decode<'a>> {
+    remaining: usize,
+    decoder: T::Decoder,
+}
+
+impl<'a, T: Decode<'a>> Iterator for DecodeIter<'a, T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.decoder.decode())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: Decode<'a>> ExactSizeIterator for DecodeIter<'a, T> {}
+impl<'a, T: Decode<'a>> FusedIterator for DecodeIter<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, decode_iter, encode, encode_iter, Decode, Encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[derive(Encode, Decode, Debug, PartialEq, Clone)]
+    enum Message {
+        Chat(String),
+        Move { x: i32, y: i32 },
+        Leave,
+    }
+
+    fn messages(n: usize) -> Vec<Message> {
+        crate::random_data::<(u8, i32, i32)>(n)
+            .into_iter()
+            .map(|(k, x, y)| match k % 3 {
+                0 => Message::Chat("a".repeat(k as usize % 10)),
+                1 => Message::Move { x, y },
+                _ => Message::Leave,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decode_iter_vec() {
+        let v = messages(1000);
+        let bytes = encode(&v);
+        let iter = decode_iter::<Message>(&bytes).unwrap();
+        assert_eq!(iter.len(), v.len());
+        assert_eq!(iter.collect::<Vec<_>>(), v);
+
+        // Stopping early is fine.
+        let mut iter = decode_iter::<Message>(&bytes).unwrap();
+        assert_eq!(iter.next().as_ref(), v.first());
+        assert_eq!(iter.len(), v.len() - 1);
+
+        // Borrowed.
+        let v = vec!["a", "bc", ""];
+        let bytes = encode(&v);
+        assert!(decode_iter::<&str>(&bytes).unwrap().eq(v));
+        assert_eq!(
+            decode_iter::<u8>(&encode(&Vec::<u8>::new())).unwrap().len(),
+            0
+        );
+
+        // Validates like decode.
+        let mut bytes = encode(&messages(10));
+        bytes.push(0);
+        assert!(decode::<Vec<Message>>(&bytes).is_err());
+        assert!(decode_iter::<Message>(&bytes).is_err());
+        assert!(decode_iter::<char>(&encode(&vec![0xD800u32])).is_err());
+    }
+
+    #[test]
+    fn encode_iter_vec() {
+        fn check<T: Encode + Clone>(v: &[T]) {
+            assert_eq!(encode_iter(v), encode(&v.to_vec()));
+            let rev: Vec<T> = v.iter().rev().cloned().collect();
+            assert_eq!(encode_iter(v.iter().rev()), encode(&rev));
+        }
+        check(&messages(1000));
+        check(&messages(1));
+        check::<Message>(&[]);
+        check(&crate::random_data::<u32>(5000));
+        check(&crate::random_data::<(u8, f32, bool)>(5000));
+        check(&[()]);
+
+        // Items can come from anywhere, e.g. a field of each component.
+        let pairs = crate::random_data::<(u16, i64)>(100);
+        let bytes = encode_iter(pairs.iter().map(|(_, b)| b));
+        let expected: Vec<_> = decode_iter::<i64>(&bytes).unwrap().collect();
+        assert!(expected.iter().eq(pairs.iter().map(|(_, b)| b)));
+    }
+
+    #[test]
+    #[should_panic = "iterator shorter than its len"]
+    fn encode_iter_wrong_len() {
+        struct Liar<'a>(core::slice::Iter<'a, u8>);
+        impl<'a> Iterator for Liar<'a> {
+            type Item = &'a u8;
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (10, Some(10))
+            }
+        }
+        impl ExactSizeIterator for Liar<'_> {}
+        encode_iter(Liar([1, 2, 3].iter()));
+    }
+}
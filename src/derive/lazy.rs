@@ -0,0 +1,215 @@
+use crate::coder::{Decoder, View};
+use crate::consume::expect_eof;
+use crate::derive::vec::VecDecoder;
+use crate::derive::Decode;
+use crate::Error;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+/// A type which can be decoded lazily with [`view`].
+pub trait Lazy<'a>: Decode<'a> {
+    /// The lazily decoded type, e.g. [`LazyVec<T>`] for `Vec<T>`.
+    type Lazy;
+
+    #[doc(hidden)]
+    fn view(bytes: &'a [u8]) -> Result<Self::Lazy, Error>;
+}
+
+/// Validates `bytes` like [`decode`](crate::decode), but only decodes values once they're
+/// accessed. E.g. `view::<Vec<T>>(bytes)` returns a [`LazyVec<T>`] which can decode element `i`
+/// without decoding elements `0..i`.
+///
+/// `T` can't contain types with a variable number of values (e.g. `Vec`, `String`, `Option` or
+/// enums with fields), since finding element `i` would require decoding the ones before it.
+/// Such types fail to compile.
+///
+/// ```
+/// let bytes = bitcode::encode(&vec![(1u32, 2.5f32), (3, 4.5)]);
+/// let lazy = bitcode::view::<Vec<(u32, f32)>>(&bytes).unwrap();
+/// assert_eq!(lazy.get(1), Some((3, 4.5)));
+/// ```
+/// ```compile_fail
+/// let bytes = bitcode::encode(&vec![vec![1u8]]);
+/// let lazy = bitcode::view::<Vec<Vec<u8>>>(&bytes);
+/// ```
+///
+/// **Warning:** The format is subject to change between major versions.
+pub fn view<'a, T: Lazy<'a>>(bytes: &'a [u8]) -> Result<T::Lazy, Error> {
+    T::view(bytes)
+}
+
+impl<'a, T: Decode<'a>> Lazy<'a> for Vec<T> {
+    type Lazy = LazyVec<'a, T>;
+
+    fn view(mut bytes: &'a [u8]) -> Result<Self::Lazy, Error> {
+        const {
+            assert!(
+                <T::Decoder as Decoder<'a, T>>::SEEKABLE,
+                "can't view Vec<T> since T has a variable number of values per element"
+            );
+        }
+        let mut decoder = VecDecoder::<'a, T>::default();
+        decoder.populate(&mut bytes, 1)?;
+        expect_eof(bytes)?;
+        Ok(LazyVec {
+            len: decoder.lengths.decode(),
+            decoder: RefCell::new((decoder.elements, 0)),
+        })
+    }
+}
+
+/// A `Vec<T>` which decodes its elements on access. Returned by [`view`].
+pub struct LazyVec<'a, T: Decode<'a>> {
+    len: usize,
+    // The decoder and the index of the element it decodes next.
+    decoder: RefCell<(T::Decoder, usize)>,
+}
+
+impl<'a, T: Decode<'a>> LazyVec<'a, T> {
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decodes the element at `index`, or returns `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let mut decoder = self.decoder.borrow_mut();
+        let (decoder, next) = &mut *decoder;
+        // Can't overflow since the elements were populated (or they're zero sized and seeking
+        // does nothing).
+        let n = index.wrapping_sub(*next) as isize;
+        // Safety: view checked SEEKABLE and index < len so it's within the populated elements.
+        unsafe { decoder.seek(n) };
+        *next = index + 1;
+        Some(decoder.decode())
+    }
+
+    /// Returns an iterator which decodes each element.
+    pub fn iter(&self) -> LazyVecIter<'_, 'a, T> {
+        LazyVecIter {
+            vec: self,
+            range: 0..self.len,
+        }
+    }
+}
+
+impl<'b, 'a, T: Decode<'a>> IntoIterator for &'b LazyVec<'a, T> {
+    type Item = T;
+    type IntoIter = LazyVecIter<'b, 'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a [`LazyVec<T>`] returned by [`LazyVec::iter`].
+pub struct LazyVecIter<'b, 'a, T: Decode<'a>> {
+    vec: &'b LazyVec<'a, T>,
+    range: Range<usize>,
+}
+
+impl<'a, T: Decode<'a>> Iterator for LazyVecIter<'_, 'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.range.next().and_then(|i| self.vec.get(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.range.nth(n).and_then(|i| self.vec.get(i))
+    }
+}
+
+impl<'a, T: Decode<'a>> DoubleEndedIterator for LazyVecIter<'_, 'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.range.next_back().and_then(|i| self.vec.get(i))
+    }
+}
+
+impl<'a, T: Decode<'a>> ExactSizeIterator for LazyVecIter<'_, 'a, T> {}
+impl<'a, T: Decode<'a>> FusedIterator for LazyVecIter<'_, 'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{encode, view, Decode, Encode};
+    use alloc::vec::Vec;
+    use core::marker::PhantomData;
+
+    #[derive(Encode, Decode, Debug, PartialEq, Clone, Copy)]
+    enum Kind {
+        A,
+        B,
+        C,
+    }
+
+    #[derive(Encode, Decode, Debug, PartialEq, Clone, Copy)]
+    struct Record {
+        id: u64,
+        position: [f32; 3],
+        kind: Kind,
+        alive: bool,
+        name: (char, i16),
+        #[bitcode(skip)]
+        skipped: PhantomData<()>,
+    }
+
+    fn records(n: usize) -> Vec<Record> {
+        crate::random_data::<(u64, [f32; 3], u8, bool, i16)>(n)
+            .into_iter()
+            .map(|(id, position, kind, alive, v)| Record {
+                id,
+                position,
+                kind: [Kind::A, Kind::B, Kind::C][kind as usize % 3],
+                alive,
+                name: (char::from(b'a' + kind % 26), v),
+                skipped: PhantomData,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lazy_vec() {
+        let v = records(1000);
+        let bytes = encode(&v);
+        let lazy = view::<Vec<Record>>(&bytes).unwrap();
+        assert_eq!(lazy.len(), v.len());
+
+        // Random access in any order.
+        for i in [999, 0, 500, 501, 3, 998, 1, 1] {
+            assert_eq!(lazy.get(i), Some(v[i]));
+        }
+        assert_eq!(lazy.get(1000), None);
+        assert_eq!(lazy.iter().collect::<Vec<_>>(), v);
+        assert!(lazy.iter().rev().eq(v.iter().rev().copied()));
+        assert_eq!(lazy.iter().nth(10), Some(v[10]));
+        assert_eq!(lazy.iter().len(), v.len());
+
+        // Other fixed width types.
+        let v: Vec<(u8, [u16; 2], ())> = (0..100).map(|i| (i, [i as u16 * 300; 2], ())).collect();
+        let bytes = encode(&v);
+        let lazy = view::<Vec<(u8, [u16; 2], ())>>(&bytes).unwrap();
+        assert_eq!(lazy.get(42), Some(v[42]));
+        let empty = encode(&Vec::<u32>::new());
+        assert!(view::<Vec<u32>>(&empty).unwrap().is_empty());
+
+        // Validates like decode.
+        assert!(view::<Vec<char>>(&encode(&vec![0xD800u32])).is_err());
+        let mut bytes = encode(&records(10));
+        bytes.push(0);
+        assert!(view::<Vec<Record>>(&bytes).is_err());
+    }
+}
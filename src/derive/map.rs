@@ -1,11 +1,11 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::derive::{Decode, Encode};
 use crate::length::{LengthDecoder, LengthEncoder};
+use crate::output::Output;
 use alloc::collections::BTreeMap;
-use alloc::vec::Vec;
 use core::num::NonZeroUsize;
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "indexmap"))]
 use core::hash::{BuildHasher, Hash};
 #[cfg(feature = "std")]
 use std::collections::HashMap;
@@ -28,7 +28,7 @@ impl<K: Encode, V: Encode> Default for MapEncoder<K, V> {
 }
 
 impl<K: Encode, V: Encode> Buffer for MapEncoder<K, V> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.lengths.collect_into(out);
         self.keys.collect_into(out);
         self.values.collect_into(out);
@@ -38,6 +38,33 @@ impl<K: Encode, V: Encode> Buffer for MapEncoder<K, V> {
         self.lengths.reserve(additional);
         // We don't know the lengths of the maps, so we can't reserve more.
     }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size() + self.keys.heap_size() + self.values.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.lengths.encoded_len() + self.keys.encoded_len() + self.values.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.lengths.clear();
+        self.keys.clear();
+        self.values.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.lengths.shrink_to_fit();
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        self.lengths.collect_into(&mut out.bytes());
+        self.keys.collect_into_parallel(out);
+        self.values.collect_into_parallel(out);
+    }
 }
 
 pub struct MapDecoder<'a, K: Decode<'a>, V: Decode<'a>> {
@@ -63,6 +90,31 @@ impl<'a, K: Decode<'a>, V: Decode<'a>> View<'a> for MapDecoder<'a, K, V> {
         self.keys.populate(input, self.lengths.length())?;
         self.values.populate(input, self.lengths.length())
     }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size() + self.keys.heap_size() + self.values.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.keys.set_recursion_depth(depth);
+        self.values.set_recursion_depth(depth);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        self.lengths.populate(input, length)?;
+        let length = self.lengths.length();
+        self.keys.populate_parallel(input, length, tasks)?;
+        self.values.populate_parallel(input, length, tasks)
+    }
 }
 
 macro_rules! encode_body {
@@ -95,6 +147,19 @@ macro_rules! decode_body {
         }
     };
 }
+// Reuses the map's capacity, but not its keys or values.
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "indexmap"))]
+macro_rules! decode_into_body {
+    ($t:ty) => {
+        #[inline(always)]
+        fn decode_into(&mut self, out: &mut $t) {
+            out.clear();
+            out.extend(
+                (0..self.lengths.decode()).map(|_| (self.keys.decode(), self.values.decode())),
+            );
+        }
+    };
+}
 
 impl<K: Encode, V: Encode> Encoder<BTreeMap<K, V>> for MapEncoder<K, V> {
     encode_body!(BTreeMap<K, V>);
@@ -112,6 +177,32 @@ impl<'a, K: Decode<'a> + Eq + Hash, V: Decode<'a>, S: BuildHasher + Default>
     Decoder<'a, HashMap<K, V, S>> for MapDecoder<'a, K, V>
 {
     decode_body!(HashMap<K, V, S>);
+    decode_into_body!(HashMap<K, V, S>);
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Encode, V: Encode, S> Encoder<hashbrown::HashMap<K, V, S>> for MapEncoder<K, V> {
+    encode_body!(hashbrown::HashMap<K, V, S>);
+}
+#[cfg(feature = "hashbrown")]
+impl<'a, K: Decode<'a> + Eq + Hash, V: Decode<'a>, S: BuildHasher + Default>
+    Decoder<'a, hashbrown::HashMap<K, V, S>> for MapDecoder<'a, K, V>
+{
+    decode_body!(hashbrown::HashMap<K, V, S>);
+    decode_into_body!(hashbrown::HashMap<K, V, S>);
+}
+
+// Encodes and decodes in insertion order.
+#[cfg(feature = "indexmap")]
+impl<K: Encode, V: Encode, S> Encoder<indexmap::IndexMap<K, V, S>> for MapEncoder<K, V> {
+    encode_body!(indexmap::IndexMap<K, V, S>);
+}
+#[cfg(feature = "indexmap")]
+impl<'a, K: Decode<'a> + Eq + Hash, V: Decode<'a>, S: BuildHasher + Default>
+    Decoder<'a, indexmap::IndexMap<K, V, S>> for MapDecoder<'a, K, V>
+{
+    decode_body!(indexmap::IndexMap<K, V, S>);
+    decode_into_body!(indexmap::IndexMap<K, V, S>);
 }
 
 #[cfg(test)]
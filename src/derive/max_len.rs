@@ -0,0 +1,53 @@
+//! Helpers for computing [`Encode::MAX_ENCODED_LEN`](crate::Encode::MAX_ENCODED_LEN) in `const`.
+//!
+//! Bounds are per value and additive: a column of `n` values never outputs more than `n` times
+//! the bound of one value. This holds since an empty column outputs nothing and a non-empty column
+//! adds at most a few header bytes (which are included in each value's bound).
+
+/// Bound of an integer encoded with `pack_ints`/`pack_bytes` which is `size` bytes once
+/// `usize`/`isize` are converted to `u64`/`i64`. A column adds at most a packing byte, a minimum
+/// (`size` bytes), and a nested `pack_bytes` header (2 bytes).
+pub const fn int(size: usize) -> Option<usize> {
+    Some(size * 2 + 3)
+}
+
+/// Bound of a value containing all of `lens` (e.g. a struct's fields).
+pub const fn sum(lens: &[Option<usize>]) -> Option<usize> {
+    let mut total = 0usize;
+    let mut i = 0;
+    while i < lens.len() {
+        let Some(len) = lens[i] else {
+            return None;
+        };
+        let Some(new_total) = total.checked_add(len) else {
+            return None;
+        };
+        total = new_total;
+        i += 1;
+    }
+    Some(total)
+}
+
+/// Bound of a value containing one of `lens` (e.g. an enum's variants).
+pub const fn max(lens: &[Option<usize>]) -> Option<usize> {
+    let mut max = 0;
+    let mut i = 0;
+    while i < lens.len() {
+        let Some(len) = lens[i] else {
+            return None;
+        };
+        if len > max {
+            max = len;
+        }
+        i += 1;
+    }
+    Some(max)
+}
+
+/// Bound of a value containing `n` of `len` (e.g. an array).
+pub const fn mul(len: Option<usize>, n: usize) -> Option<usize> {
+    match len {
+        Some(len) => len.checked_mul(n),
+        None => None,
+    }
+}
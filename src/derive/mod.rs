@@ -1,5 +1,6 @@
 use crate::coder::{Buffer, Decoder, Encoder, View};
 use crate::consume::expect_eof;
+use crate::output::Output;
 use crate::Error;
 use alloc::vec::Vec;
 use core::num::NonZeroUsize;
@@ -10,37 +11,83 @@ pub(crate) mod convert;
 mod duration;
 mod empty;
 mod impls;
+#[cfg(feature = "std")]
+mod interned;
 // TODO: When ip_in_core has been stable (https://github.com/rust-lang/rust/issues/108443)
 // for long enough, remove feature check.
 #[cfg(feature = "std")]
 mod ip_addr;
-mod map;
+mod iter;
+mod lazy;
+pub(crate) mod map;
+pub(crate) mod max_len;
 mod option;
+mod ordering;
+#[cfg(all(feature = "std", any(unix, windows)))]
+mod os_str;
+mod range;
+#[cfg(feature = "derive")]
+mod recursive;
 mod result;
-mod smart_ptr;
+pub(crate) mod smart_ptr;
+#[cfg(feature = "std")]
+mod system_time;
 mod variant;
 pub(crate) mod vec;
+pub(crate) mod wrapper;
 
 // For derive macro.
 #[cfg(feature = "derive")]
 #[doc(hidden)]
 pub mod __private {
     extern crate alloc;
-    pub use crate::coder::{uninit_field, Buffer, Decoder, Encoder, Result, View};
+    pub use crate::coder::{if_rayon, uninit_field, Buffer, Decoder, Encoder, Result, View};
+    pub use crate::derive::max_len::{max as max_len_max, sum as max_len_sum};
+    pub use crate::derive::recursive::{RecursiveDecoder, RecursiveEncoder};
     pub use crate::derive::variant::{VariantDecoder, VariantEncoder};
     pub use crate::derive::{Decode, Encode};
     pub fn invalid_enum_variant<T>() -> Result<T> {
         crate::error::err("invalid enum variant")
     }
+    pub use crate::output::Output;
     pub use alloc::vec::Vec;
+
+    #[cfg(feature = "rayon")]
+    pub use crate::parallel::{ParallelInput, ParallelOutput};
+    #[cfg(feature = "serde")]
+    pub use crate::serde::derive::{DeserializeDecoder, SerializeEncoder};
+    #[cfg(feature = "serde")]
+    pub use ::serde::{Deserialize, Serialize};
+
+    /// Bound for `#[bitcode(serde)]` fields, since their decoder holds values.
+    #[cfg(feature = "serde")]
+    pub trait DeserializeSendSync<'de>: Deserialize<'de> + Send + Sync {}
+    #[cfg(feature = "serde")]
+    impl<'de, T: Deserialize<'de> + Send + Sync> DeserializeSendSync<'de> for T {}
 }
 
+#[cfg(feature = "std")]
+pub use interned::Interned;
+pub use iter::{decode_iter, encode_iter, DecodeIter};
+pub use lazy::{view, Lazy, LazyVec, LazyVecIter};
+
 /// A type which can be encoded to bytes with [`encode`].
 ///
 /// Use `#[derive(Encode)]` to implement.
+///
+/// # Panics
+///
+/// Encoding panics if a `#[bitcode(serde)]` field fails to serialize. Besides errors returned by
+/// the field's `Serialize` impl, this happens if it uses something
+/// [`serialize`](crate::serialize) doesn't support, e.g. `#[serde(skip_serializing_if)]` or enums
+/// with more than 256 variants.
 pub trait Encode {
     #[doc(hidden)]
     type Encoder: Encoder<Self>;
+
+    /// See [`max_encoded_len`].
+    #[doc(hidden)]
+    const MAX_ENCODED_LEN: Option<usize> = None;
 }
 
 /// A type which can be decoded from bytes with [`decode`].
@@ -60,13 +107,17 @@ impl<T> DecodeOwned for T where T: for<'de> Decode<'de> {}
 // Stop #[inline(always)] of Encoder::encode/Decoder::decode since 90% of the time is spent in these
 // functions, and we don't want extra code interfering with optimizations.
 #[inline(never)]
-fn encode_inline_never<T: Encode + ?Sized>(encoder: &mut T::Encoder, t: &T) {
+pub(crate) fn encode_inline_never<T: Encode + ?Sized>(encoder: &mut T::Encoder, t: &T) {
     encoder.encode(t);
 }
 #[inline(never)]
 fn decode_inline_never<'a, T: Decode<'a>>(decoder: &mut T::Decoder) -> T {
     decoder.decode()
 }
+#[inline(never)]
+fn decode_into_inline_never<'a, T: Decode<'a>>(decoder: &mut T::Decoder, out: &mut T) {
+    decoder.decode_into(out);
+}
 
 /// Encodes a `T:` [`Encode`] into a [`Vec<u8>`].
 ///
@@ -78,6 +129,40 @@ pub fn encode<T: Encode + ?Sized>(t: &T) -> Vec<u8> {
     encoder.collect()
 }
 
+/// Encodes a `T:` [`Encode`] into the start of `out`, returning the number of bytes written.
+/// Errors if `out` is too small ([`Error::needed_len`] says how many bytes are needed).
+///
+/// **Warning:** The format is subject to change between major versions.
+pub fn encode_into_slice<T: Encode + ?Sized>(t: &T, out: &mut [u8]) -> Result<usize, Error> {
+    let mut encoder = T::Encoder::default();
+    encoder.reserve(NonZeroUsize::new(1).unwrap());
+    encode_inline_never(&mut encoder, t);
+    collect_into_slice(&mut encoder, out)
+}
+
+/// Returns an upper bound on the number of bytes [`encode`] outputs for any `T`, or [`None`] if
+/// `T` doesn't have a fixed layout (e.g. it contains a [`Vec`] or [`String`](alloc::string::String)).
+///
+/// The bound assumes nothing gets packed, so it's usually much larger than the actual length (see
+/// [`Buffer::encoded_len`](crate::Buffer::encoded_len) for that). It's free to compute and can be
+/// used in `const` contexts.
+pub const fn max_encoded_len<T: Encode + ?Sized>() -> Option<usize> {
+    T::MAX_ENCODED_LEN
+}
+
+/// Measures `encoder` so it can be collected directly into `out` if it fits.
+fn collect_into_slice(encoder: &mut impl Buffer, out: &mut [u8]) -> Result<usize, Error> {
+    let n = encoder.encoded_len();
+    let Some(out) = out.get_mut(..n) else {
+        encoder.clear();
+        return Err(crate::error::buffer_too_small(n));
+    };
+    let mut output = Output::Slice(out);
+    encoder.collect_into(&mut output);
+    debug_assert!(matches!(output, Output::Slice(remaining) if remaining.is_empty()));
+    Ok(n)
+}
+
 /// Decodes a [`&[u8]`][`prim@slice`] into an instance of `T:` [`Decode`].
 ///
 /// **Warning:** The format is subject to change between major versions.
@@ -91,25 +176,141 @@ pub fn decode<'a, T: Decode<'a>>(mut bytes: &'a [u8]) -> Result<T, Error> {
 impl crate::buffer::Buffer {
     /// Like [`encode`], but saves allocations between calls.
     pub fn encode<'a, T: Encode + ?Sized>(&'a mut self, t: &T) -> &'a [u8] {
+        self.check_memory_limit();
         // Safety: Encoders don't have any lifetimes (they don't contain T either).
-        let encoder = unsafe { self.registry.get_non_static::<T::Encoder>() };
+        let encoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Encoder>(Buffer::heap_size, Buffer::shrink_to_fit)
+        };
         encoder.reserve(NonZeroUsize::new(1).unwrap());
         encode_inline_never(encoder, t);
         self.out.clear();
-        encoder.collect_into(&mut self.out);
+        encoder.collect_into(&mut Output::Vec(&mut self.out));
         self.out.as_slice()
     }
 
+    /// Like [`Self::encode`], but appends to `out` instead of returning a slice of an internal
+    /// buffer.
+    #[cfg(feature = "bytes")]
+    pub fn encode_into<T: Encode + ?Sized>(&mut self, t: &T, out: &mut bytes::BytesMut) {
+        self.check_memory_limit();
+        // Safety: See Buffer::encode.
+        let encoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Encoder>(Buffer::heap_size, Buffer::shrink_to_fit)
+        };
+        encoder.reserve(NonZeroUsize::new(1).unwrap());
+        encode_inline_never(encoder, t);
+
+        // Collects directly into `out` instead of copying from `self.out`.
+        let start = out.len();
+        out.resize(start + encoder.encoded_len(), 0);
+        collect_into_slice(encoder, &mut out[start..]).unwrap();
+    }
+
+    /// Like [`encode_into_slice`], but saves allocations between calls.
+    pub fn encode_into_slice<T: Encode + ?Sized>(
+        &mut self,
+        t: &T,
+        out: &mut [u8],
+    ) -> Result<usize, Error> {
+        self.check_memory_limit();
+        // Safety: See Buffer::encode.
+        let encoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Encoder>(Buffer::heap_size, Buffer::shrink_to_fit)
+        };
+        encoder.reserve(NonZeroUsize::new(1).unwrap());
+        encode_inline_never(encoder, t);
+        collect_into_slice(encoder, out)
+    }
+
+    /// Returns the number of bytes [`Self::encode`] outputs for `t` (e.g. to check if it fits in a
+    /// packet). Packing depends on every value in a column, so `t` is still split into columns,
+    /// but they're only measured instead of being packed into the output.
+    pub fn encoded_len<T: Encode + ?Sized>(&mut self, t: &T) -> usize {
+        self.check_memory_limit();
+        // Safety: See Buffer::encode.
+        let encoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Encoder>(Buffer::heap_size, Buffer::shrink_to_fit)
+        };
+        encoder.reserve(NonZeroUsize::new(1).unwrap());
+        encode_inline_never(encoder, t);
+        let len = encoder.encoded_len();
+        encoder.clear();
+        len
+    }
+
     /// Like [`decode`], but saves allocations between calls.
     pub fn decode<'a, T: Decode<'a>>(&mut self, mut bytes: &'a [u8]) -> Result<T, Error> {
+        self.check_memory_limit();
         // Safety: Decoders have dangling pointers to `bytes` from previous calls which haven't been
         // cleared. This isn't an issue in practice because they remain as pointers in FastSlice and
         // aren't dereferenced. If we wanted to be safer we could clear all the decoders but this
         // would result in lots of extra code to maintain and a performance/binary size hit.
         // To detect misuse we run miri tests/cargo fuzz where bytes goes out of scope between calls.
-        let decoder = unsafe { self.registry.get_non_static::<T::Decoder>() };
+        let decoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Decoder>(View::heap_size, |_| {})
+        };
+        decoder.populate(&mut bytes, 1)?;
+        expect_eof(bytes)?;
+        Ok(decode_inline_never(decoder))
+    }
+
+    /// Like [`Self::decode`], but overwrites `t` in place, reusing the allocations of its
+    /// collections (e.g. a `Vec`'s elements and capacity). If decoding fails, `t` is unchanged.
+    pub fn decode_into<'a, T: Decode<'a>>(
+        &mut self,
+        t: &mut T,
+        mut bytes: &'a [u8],
+    ) -> Result<(), Error> {
+        self.check_memory_limit();
+        // Safety: See Buffer::decode.
+        let decoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Decoder>(View::heap_size, |_| {})
+        };
         decoder.populate(&mut bytes, 1)?;
         expect_eof(bytes)?;
+        decode_into_inline_never(decoder, t);
+        Ok(())
+    }
+
+    /// Like [`Self::encode`], but packs large columns on rayon's thread pool. Outputs the same
+    /// bytes as [`Self::encode`].
+    #[cfg(feature = "rayon")]
+    pub fn encode_parallel<'a, T: Encode + ?Sized>(&'a mut self, t: &T) -> &'a [u8] {
+        self.check_memory_limit();
+        // Safety: Encoders don't have any lifetimes (they don't contain T either).
+        let encoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Encoder>(Buffer::heap_size, Buffer::shrink_to_fit)
+        };
+        encoder.reserve(NonZeroUsize::new(1).unwrap());
+        encode_inline_never(encoder, t);
+        self.out.clear();
+        let mut out = crate::parallel::ParallelOutput::default();
+        encoder.collect_into_parallel(&mut out);
+        out.finish(&mut self.out);
+        self.out.as_slice()
+    }
+
+    /// Like [`Self::decode`], but unpacks and validates large columns on rayon's thread pool.
+    /// Succeeds or fails the same as [`Self::decode`].
+    #[cfg(feature = "rayon")]
+    pub fn decode_parallel<'a, T: Decode<'a>>(&mut self, mut bytes: &'a [u8]) -> Result<T, Error> {
+        self.check_memory_limit();
+        // Safety: See Buffer::decode.
+        let decoder = unsafe {
+            self.registry
+                .get_non_static::<T, T::Decoder>(View::heap_size, |_| {})
+        };
+        let mut tasks = crate::parallel::ParallelInput::default();
+        decoder.populate_parallel(&mut bytes, 1, &mut tasks)?;
+        expect_eof(bytes)?;
+        tasks.finish()?;
         Ok(decode_inline_never(decoder))
     }
 }
@@ -136,9 +337,33 @@ impl crate::buffer::Buffer {
 #[doc(hidden)]
 pub fn _cant_duplicate_skip() {}
 
+/// ```compile_fail
+/// use bitcode::Encode;
+/// #[derive(Encode)]
+/// #[bitcode(project_from = "(u32, u32)")]
+/// struct Test {
+///     x: u32,
+/// }
+/// ```
+/// ```compile_fail
+/// use bitcode::Decode;
+/// #[derive(Decode)]
+/// struct Full {
+///     x: u32,
+/// }
+/// #[derive(Decode)]
+/// #[bitcode(project_from = "Full")]
+/// struct Test {
+///     y: u32,
+/// }
+/// ```
+#[doc(hidden)]
+pub fn _project_from_fields_must_exist() {}
+
 #[cfg(test)]
 mod tests {
     use crate::{Decode, Encode};
+    use alloc::string::String;
     use alloc::vec::Vec;
 
     #[test]
@@ -425,4 +650,349 @@ mod tests {
             present: Indirect<A>,
         }
     }
+
+    #[test]
+    fn encode_into_slice() {
+        let v = vec![1u32, 2, 3];
+        let encoded = super::encode(&v);
+
+        let mut out = [0xFF; 64];
+        let n = super::encode_into_slice(&v, &mut out).unwrap();
+        assert_eq!(&out[..n], encoded);
+        assert!(out[n..].iter().all(|&b| b == 0xFF));
+
+        let mut buffer = crate::Buffer::new();
+        let mut out = vec![0; encoded.len()];
+        assert_eq!(
+            buffer.encode_into_slice(&v, &mut out).unwrap(),
+            encoded.len()
+        );
+        assert_eq!(out, encoded);
+
+        // Too small.
+        let mut out = vec![0; encoded.len() - 1];
+        let e = super::encode_into_slice(&v, &mut out).unwrap_err();
+        assert_eq!(e.needed_len(), Some(encoded.len()));
+        let e = buffer.encode_into_slice(&v, &mut out).unwrap_err();
+        assert_eq!(e.needed_len(), Some(encoded.len()));
+        assert!(out.iter().all(|&b| b == 0));
+        assert_eq!(crate::error::error("").needed_len(), None);
+
+        // Every kind of column, retrying with the needed length.
+        for n in [1, 2, 10, 1000] {
+            let v: Vec<_> = crate::random_data::<(u64, i16, u8, bool, f32, Option<char>)>(n)
+                .into_iter()
+                .map(|(a, b, c, d, e, f)| (a % 100, b, "é".repeat(c as usize % 4), d, e, f))
+                .collect();
+            let encoded = super::encode(&v);
+            let mut out = vec![];
+            let needed = buffer.encode_into_slice(&v, &mut out).unwrap_err();
+            out.resize(needed.needed_len().unwrap(), 0);
+            assert_eq!(buffer.encode_into_slice(&v, &mut out).unwrap(), out.len());
+            assert_eq!(out, encoded);
+            assert_eq!(super::encode_into_slice(&v, &mut out).unwrap(), out.len());
+            assert_eq!(out, encoded);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn encode_into() {
+        let mut buffer = crate::Buffer::new();
+        let mut out = bytes::BytesMut::from(&b"prefix"[..]);
+        buffer.encode_into(&vec![1u32, 2, 3], &mut out);
+        assert_eq!(&out[..6], b"prefix");
+        assert_eq!(&out[6..], super::encode(&vec![1u32, 2, 3]));
+
+        out.clear();
+        buffer.encode_into("abc", &mut out);
+        assert_eq!(&out[..], super::encode("abc"));
+
+        // The bytes aren't copied through the buffer's own output.
+        assert_eq!(buffer.out.capacity(), 0);
+    }
+
+    #[test]
+    fn encoded_len() {
+        #[derive(Encode)]
+        enum Kind {
+            A,
+            B(i16),
+            C { name: String },
+        }
+
+        fn check<T: Encode + ?Sized>(buffer: &mut crate::Buffer, v: &T) {
+            let len = buffer.encoded_len(v);
+            assert_eq!(len, super::encode(v).len());
+            // Measuring clears the encoders, so encoding afterwards isn't affected.
+            assert_eq!(buffer.encode(v), super::encode(v));
+        }
+
+        let mut buffer = crate::Buffer::new();
+        for v in [vec![], vec![1u32], vec![1, 2, 3], vec![u32::MAX; 100]] {
+            check(&mut buffer, &v);
+        }
+        for n in [0, 1, 2, 3, 7, 100, 1000] {
+            let v = crate::random_data::<(u64, i32, u16, i8, u8, bool, f32, Option<char>)>(n);
+            check(&mut buffer, &v);
+            let v: Vec<_> = v
+                .into_iter()
+                .map(|(a, b, c, d, e, f, _, _)| {
+                    let kind = match e % 3 {
+                        0 => Kind::A,
+                        1 => Kind::B(d as i16 * 100),
+                        _ => Kind::C {
+                            name: "é".repeat(c as usize % 5),
+                        },
+                    };
+                    (a % 1000, b / 7, vec![d; e as usize % 4], f.then_some(kind))
+                })
+                .collect();
+            check(&mut buffer, &v);
+        }
+    }
+
+    #[test]
+    fn max_encoded_len() {
+        use super::max_encoded_len;
+
+        #[derive(Encode)]
+        enum Shape {
+            Empty,
+            Circle(f32),
+            Rect { size: [u16; 2], filled: bool },
+        }
+
+        #[derive(Encode)]
+        struct Fixed {
+            id: u64,
+            shape: Shape,
+            parent: Option<char>,
+            #[bitcode(skip)]
+            #[allow(unused)]
+            skipped: String,
+        }
+
+        #[derive(Encode)]
+        struct Unbounded {
+            #[allow(unused)]
+            name: String,
+        }
+
+        // Usable in const.
+        const _: () = assert!(max_encoded_len::<Fixed>().is_some());
+        assert_eq!(max_encoded_len::<()>(), Some(0));
+        assert_eq!(max_encoded_len::<bool>(), Some(1));
+        assert_eq!(max_encoded_len::<Vec<u8>>(), None);
+        assert_eq!(max_encoded_len::<(u8, String)>(), None);
+        assert_eq!(max_encoded_len::<Unbounded>(), None);
+
+        fn check<T: Encode>(v: &T) {
+            let len = super::encode(v).len();
+            let max = max_encoded_len::<T>().unwrap();
+            assert!(len <= max, "{len} > {max}");
+        }
+        for v in crate::random_data::<(u64, u32, i16, u8, bool, f32)>(100) {
+            check(&v);
+            check(&[v; 7]);
+            check(&Some(v));
+            check(&Ok::<_, [u128; 3]>(v));
+        }
+        check(&[u64::MAX, 0, 1, 2, 3, 4, 5, 6]);
+        check(&[i8::MIN, 0, i8::MAX, -1, 1]);
+        for shape in [
+            Shape::Empty,
+            Shape::Circle(1.0),
+            Shape::Rect {
+                size: [0, u16::MAX],
+                filled: true,
+            },
+        ] {
+            check(&Fixed {
+                id: u64::MAX,
+                shape,
+                parent: Some('a'),
+                skipped: "abc".into(),
+            });
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_into() {
+        use std::collections::HashMap;
+
+        #[derive(Encode, Decode, Debug, Default, PartialEq)]
+        enum Event {
+            #[default]
+            None,
+            Chat(String),
+            Move {
+                path: Vec<(i32, i32)>,
+            },
+        }
+
+        #[derive(Encode, Decode, Debug, Default, PartialEq)]
+        struct State {
+            names: Vec<String>,
+            scores: HashMap<u32, u64>,
+            event: Event,
+            last: Option<Vec<u8>>,
+            pair: (String, [String; 2]),
+            #[bitcode(skip)]
+            skipped: u32,
+        }
+
+        let a = State {
+            names: vec!["a".repeat(100), "b".repeat(100), "c".into()],
+            scores: (0..50).map(|i| (i, i as u64 * 3)).collect(),
+            event: Event::Move {
+                path: vec![(1, 2); 100],
+            },
+            last: Some(vec![5; 100]),
+            pair: ("x".repeat(100), ["y".into(), "z".into()]),
+            skipped: 0,
+        };
+        let b = State {
+            names: vec!["d".into(), "e".into()],
+            scores: [(1, 2)].into_iter().collect(),
+            event: Event::Move { path: vec![(3, 4)] },
+            last: Some(vec![6]),
+            pair: ("w".into(), ["u".into(), "v".into()]),
+            skipped: 0,
+        };
+
+        let mut buffer = crate::Buffer::new();
+        let mut state = State::default();
+        buffer.decode_into(&mut state, &super::encode(&a)).unwrap();
+        assert_eq!(state, a);
+
+        // Allocations are reused.
+        let ptrs = |s: &State| {
+            let Event::Move { path } = &s.event else {
+                unreachable!()
+            };
+            [
+                s.names.as_ptr() as usize,
+                s.names[0].as_ptr() as usize,
+                s.last.as_ref().unwrap().as_ptr() as usize,
+                s.pair.0.as_ptr() as usize,
+                path.as_ptr() as usize,
+            ]
+        };
+        let before = ptrs(&state);
+        let capacity = state.scores.capacity();
+        state.skipped = 1;
+        buffer.decode_into(&mut state, &super::encode(&b)).unwrap();
+        assert_eq!(state, b);
+        assert_eq!(ptrs(&state), before);
+        assert_eq!(state.scores.capacity(), capacity);
+
+        // Different variants are replaced.
+        for event in [
+            Event::Chat("hi".into()),
+            Event::None,
+            Event::Chat("a".into()),
+        ] {
+            let v = State {
+                event,
+                ..Default::default()
+            };
+            buffer.decode_into(&mut state, &super::encode(&v)).unwrap();
+            assert_eq!(state, v);
+        }
+
+        // Errors leave the value unchanged.
+        let mut bytes = super::encode(&a);
+        bytes.push(0);
+        assert!(buffer.decode_into(&mut state, &bytes).is_err());
+        assert_eq!(state.event, Event::Chat("a".into()));
+
+        // Borrowed fields.
+        #[derive(Encode, Decode, Debug, PartialEq)]
+        struct Borrowed<'a>(&'a str, Vec<&'a str>);
+        let bytes = super::encode(&Borrowed("b", vec!["c", "d"]));
+        let mut borrowed = Borrowed("a", vec!["a"; 10]);
+        buffer.decode_into(&mut borrowed, &bytes).unwrap();
+        assert_eq!(borrowed, Borrowed("b", vec!["c", "d"]));
+    }
+
+    #[test]
+    fn project_from() {
+        #[derive(Encode, Decode, Debug, PartialEq)]
+        enum Kind {
+            A(String),
+            B,
+        }
+
+        #[derive(Encode, Decode, Debug, PartialEq)]
+        struct FullRecord<T> {
+            id: u64,
+            name: String,
+            kind: Kind,
+            tags: Vec<String>,
+            r#type: T,
+        }
+
+        #[derive(Decode, Debug, PartialEq)]
+        #[bitcode(project_from = "FullRecord<T>")]
+        struct Slim<T> {
+            r#type: T,
+            id: u64,
+            #[bitcode(skip)]
+            skipped: u8,
+        }
+
+        #[derive(Decode, Debug, PartialEq)]
+        #[bitcode(project_from = "FullRecord<u8>")]
+        struct Kinds<'a> {
+            kind: Kind,
+            name: &'a str,
+        }
+
+        let full: Vec<_> = (0..100)
+            .map(|i| FullRecord {
+                id: i,
+                name: "n".repeat(i as usize % 5),
+                kind: if i % 3 == 0 {
+                    Kind::A("a".repeat(i as usize % 7))
+                } else {
+                    Kind::B
+                },
+                tags: vec!["t".into(); i as usize % 4],
+                r#type: i as u8,
+            })
+            .collect();
+        let bytes = crate::encode(&full);
+
+        let slim: Vec<Slim<u8>> = crate::decode(&bytes).unwrap();
+        let expected: Vec<_> = full
+            .iter()
+            .map(|f| Slim {
+                r#type: f.r#type,
+                id: f.id,
+                skipped: 0,
+            })
+            .collect();
+        assert_eq!(slim, expected);
+        let mut buffer = crate::Buffer::new();
+        let mut into: Vec<Slim<u8>> = Vec::new();
+        buffer.decode_into(&mut into, &bytes).unwrap();
+        assert_eq!(into, expected);
+
+        let kinds: Vec<Kinds> = crate::decode(&bytes).unwrap();
+        assert!(kinds
+            .iter()
+            .zip(&full)
+            .all(|(k, f)| k.kind == f.kind && k.name == f.name));
+
+        // Unused fields are still validated.
+        let record = |name: u8| vec![(1u64, vec![name], 1u8, Vec::<String>::new(), 2u8)];
+        assert!(crate::decode::<Vec<Slim<u8>>>(&crate::encode(&record(b'a'))).is_ok());
+        let invalid = crate::encode(&record(0xFF));
+        assert!(crate::decode::<Vec<Slim<u8>>>(&invalid).is_err());
+        let mut bytes = bytes;
+        bytes.push(0);
+        assert!(crate::decode::<Vec<Slim<u8>>>(&bytes).is_err());
+    }
 }
@@ -2,7 +2,7 @@ use crate::coder::{Buffer, Decoder, Encoder, Result, View, MAX_VECTORED_CHUNK};
 use crate::derive::variant::{VariantDecoder, VariantEncoder};
 use crate::derive::{Decode, Encode};
 use crate::fast::{FastArrayVec, PushUnchecked};
-use alloc::vec::Vec;
+use crate::output::Output;
 use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
 
@@ -74,7 +74,7 @@ impl<T: Encode> Encoder<Option<T>> for OptionEncoder<T> {
 }
 
 impl<T: Encode> Buffer for OptionEncoder<T> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.variants.collect_into(out);
         self.some.collect_into(out);
     }
@@ -83,6 +83,30 @@ impl<T: Encode> Buffer for OptionEncoder<T> {
         self.variants.reserve(additional);
         // We don't know how many are Some, so we can't reserve more.
     }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.some.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.variants.encoded_len() + self.some.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.variants.clear();
+        self.some.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.variants.shrink_to_fit();
+        self.some.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        self.variants.collect_into(&mut out.bytes());
+        self.some.collect_into_parallel(out);
+    }
 }
 
 pub struct OptionDecoder<'a, T: Decode<'a>> {
@@ -105,6 +129,29 @@ impl<'a, T: Decode<'a>> View<'a> for OptionDecoder<'a, T> {
         self.variants.populate(input, length)?;
         self.some.populate(input, self.variants.length(1))
     }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.some.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.some.set_recursion_depth(depth);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        self.variants.populate(input, length)?;
+        self.some
+            .populate_parallel(input, self.variants.length(1), tasks)
+    }
 }
 
 impl<'a, T: Decode<'a>> Decoder<'a, Option<T>> for OptionDecoder<'a, T> {
@@ -116,6 +163,19 @@ impl<'a, T: Decode<'a>> Decoder<'a, Option<T>> for OptionDecoder<'a, T> {
             out.write(None);
         }
     }
+
+    #[inline(always)]
+    fn decode_into(&mut self, out: &mut Option<T>) {
+        if self.variants.decode() != 0 {
+            if let Some(out) = out {
+                self.some.decode_into(out);
+            } else {
+                *out = Some(self.some.decode());
+            }
+        } else {
+            *out = None;
+        }
+    }
 }
 
 #[cfg(test)]
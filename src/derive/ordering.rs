@@ -0,0 +1,116 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::variant::{VariantDecoder, VariantEncoder};
+use crate::derive::{Decode, Encode};
+use crate::output::Output;
+use core::cmp::Ordering;
+use core::num::NonZeroUsize;
+
+// Encoded like `#[derive(Encode)] enum Ordering { Less, Equal, Greater }`.
+impl Encode for Ordering {
+    type Encoder = OrderingEncoder;
+    const MAX_ENCODED_LEN: Option<usize> = Some(1);
+}
+impl<'a> Decode<'a> for Ordering {
+    type Decoder = OrderingDecoder<'a>;
+}
+
+#[derive(Default)]
+pub struct OrderingEncoder(VariantEncoder<u8, 3>);
+
+impl Encoder<Ordering> for OrderingEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &Ordering) {
+        self.0.encode(&((*t as i8 + 1) as u8));
+    }
+}
+
+impl Buffer for OrderingEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.0.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.0.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
+#[derive(Default)]
+pub struct OrderingDecoder<'a>(VariantDecoder<'a, u8, 3, 0>);
+
+impl<'a> View<'a> for OrderingDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.0.populate(input, length)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+impl<'a> Decoder<'a, Ordering> for OrderingDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> Ordering {
+        // VariantDecoder guarantees the variant is < 3.
+        match self.0.decode() {
+            0 => Ordering::Less,
+            1 => Ordering::Equal,
+            _ => Ordering::Greater,
+        }
+    }
+
+    const SEEKABLE: bool = true;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.0.seek(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, Decode, Encode};
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn ordering() {
+        #[derive(Encode, Decode)]
+        enum Derived {
+            Less,
+            Equal,
+            Greater,
+        }
+        for (o, d) in [
+            (Ordering::Less, Derived::Less),
+            (Ordering::Equal, Derived::Equal),
+            (Ordering::Greater, Derived::Greater),
+        ] {
+            assert_eq!(encode(&o), encode(&d));
+            assert_eq!(decode::<Ordering>(&encode(&o)).unwrap(), o);
+        }
+    }
+
+    fn bench_data() -> Vec<Ordering> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|v| (v % 3).cmp(&1))
+            .collect()
+    }
+    crate::bench_encode_decode!(ordering_vec: Vec<_>);
+}
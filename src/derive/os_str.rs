@@ -0,0 +1,206 @@
+use crate::bytes::{BytesDecoder, BytesEncoder};
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::variant::{VariantDecoder, VariantEncoder};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use crate::output::Output;
+use core::num::NonZeroUsize;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+#[cfg(unix)]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
+#[cfg(windows)]
+use std::os::windows::ffi::{OsStrExt, OsStringExt};
+
+// Like serde, encodes either Unix bytes or Windows wide chars. Only the current platform's variant
+// can be decoded.
+macro_rules! impl_os_str {
+    ($($t:ty),+) => {
+        $(
+            impl Encode for $t {
+                type Encoder = OsStrEncoder;
+            }
+        )+
+    };
+}
+impl_os_str!(OsStr, OsString, Path, PathBuf);
+
+impl<'a> Decode<'a> for OsString {
+    type Decoder = OsStringDecoder<'a>;
+}
+impl<'a> Decode<'a> for PathBuf {
+    type Decoder = OsStringDecoder<'a>;
+}
+
+#[derive(Default)]
+pub struct OsStrEncoder {
+    variants: VariantEncoder<u8, 2>,
+    unix: BytesEncoder,
+    windows: VecEncoder<u16>,
+}
+
+impl Encoder<OsStr> for OsStrEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &OsStr) {
+        let one = NonZeroUsize::new(1).unwrap();
+        #[cfg(unix)]
+        {
+            self.variants.encode(&0);
+            self.unix.reserve(one);
+            self.unix.encode(t.as_bytes());
+        }
+        #[cfg(windows)]
+        {
+            self.variants.encode(&1);
+            let wide: Vec<u16> = t.encode_wide().collect();
+            self.windows.reserve(one);
+            self.windows.encode(wide.as_slice());
+        }
+    }
+}
+
+impl Encoder<OsString> for OsStrEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &OsString) {
+        self.encode(t.as_os_str());
+    }
+}
+
+impl Encoder<Path> for OsStrEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &Path) {
+        self.encode(t.as_os_str());
+    }
+}
+
+impl Encoder<PathBuf> for OsStrEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &PathBuf) {
+        self.encode(t.as_os_str());
+    }
+}
+
+impl Buffer for OsStrEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.variants.collect_into(out);
+        self.unix.collect_into(out);
+        self.windows.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.variants.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.unix.heap_size() + self.windows.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.variants.encoded_len() + self.unix.encoded_len() + self.windows.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.variants.clear();
+        self.unix.clear();
+        self.windows.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.variants.shrink_to_fit();
+        self.unix.shrink_to_fit();
+        self.windows.shrink_to_fit();
+    }
+}
+
+#[derive(Default)]
+pub struct OsStringDecoder<'a> {
+    variants: VariantDecoder<'a, u8, 2, 2>,
+    unix: BytesDecoder<'a>,
+    windows: VecDecoder<'a, u16>,
+}
+
+impl<'a> View<'a> for OsStringDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.variants.populate(input, length)?;
+        self.unix.populate(input, self.variants.length(0))?;
+        self.windows.populate(input, self.variants.length(1))?;
+
+        #[cfg(unix)]
+        if self.variants.length(1) != 0 {
+            return err("can't decode Windows OsString on Unix");
+        }
+        #[cfg(windows)]
+        if self.variants.length(0) != 0 {
+            return err("can't decode Unix OsString on Windows");
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.unix.heap_size() + self.windows.heap_size()
+    }
+}
+
+impl<'a> Decoder<'a, OsString> for OsStringDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> OsString {
+        // populate ensured every variant is the current platform's.
+        #[cfg(unix)]
+        {
+            let bytes: &[u8] = self.unix.decode();
+            OsString::from_vec(bytes.to_vec())
+        }
+        #[cfg(windows)]
+        {
+            let wide: Vec<u16> = self.windows.decode();
+            OsString::from_wide(&wide)
+        }
+    }
+}
+
+impl<'a> Decoder<'a, PathBuf> for OsStringDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> PathBuf {
+        let os_string: OsString = self.decode();
+        os_string.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use std::ffi::OsString;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn os_string() {
+        let v = vec![OsString::from("abc"), OsString::new(), OsString::from("☺")];
+        assert_eq!(decode::<Vec<OsString>>(&encode(&v)).unwrap(), v);
+
+        let path = Path::new("a/b.c");
+        assert_eq!(decode::<PathBuf>(&encode(path)).unwrap(), path);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn os_string_unix() {
+        use std::os::unix::ffi::OsStringExt;
+        let v = OsString::from_vec(vec![0xFF, 0]);
+        assert_eq!(decode::<OsString>(&encode(&v)).unwrap(), v);
+
+        // Windows variant.
+        let windows: Result<&[u8], Vec<u16>> = Err(vec![97]);
+        assert!(decode::<OsString>(&encode(&windows)).is_err());
+    }
+
+    fn bench_data() -> Vec<PathBuf> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| PathBuf::from("a/".repeat(n as usize / 16)))
+            .collect()
+    }
+    crate::bench_encode_decode!(path_buf_vec: Vec<PathBuf>);
+}
@@ -0,0 +1,305 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::variant::{VariantDecoder, VariantEncoder};
+use crate::derive::{Decode, Encode};
+use crate::output::Output;
+use core::num::NonZeroUsize;
+use core::ops::{Bound, Range, RangeInclusive};
+
+impl<T: Encode> Encode for Range<T> {
+    type Encoder = RangeEncoder<T>;
+    const MAX_ENCODED_LEN: Option<usize> = crate::derive::max_len::mul(T::MAX_ENCODED_LEN, 2);
+}
+impl<'a, T: Decode<'a>> Decode<'a> for Range<T> {
+    type Decoder = RangeDecoder<'a, T>;
+}
+impl<T: Encode> Encode for RangeInclusive<T> {
+    type Encoder = RangeEncoder<T>;
+    const MAX_ENCODED_LEN: Option<usize> = crate::derive::max_len::mul(T::MAX_ENCODED_LEN, 2);
+}
+impl<'a, T: Decode<'a>> Decode<'a> for RangeInclusive<T> {
+    type Decoder = RangeDecoder<'a, T>;
+}
+impl<T: Encode> Encode for Bound<T> {
+    type Encoder = BoundEncoder<T>;
+    const MAX_ENCODED_LEN: Option<usize> =
+        crate::derive::max_len::sum(&[Some(1), T::MAX_ENCODED_LEN]);
+}
+impl<'a, T: Decode<'a>> Decode<'a> for Bound<T> {
+    type Decoder = BoundDecoder<'a, T>;
+}
+
+pub struct RangeEncoder<T: Encode> {
+    start: T::Encoder,
+    end: T::Encoder,
+}
+
+// Can't derive since it would bound T: Default.
+impl<T: Encode> Default for RangeEncoder<T> {
+    fn default() -> Self {
+        Self {
+            start: Default::default(),
+            end: Default::default(),
+        }
+    }
+}
+
+impl<T: Encode> Encoder<Range<T>> for RangeEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, t: &Range<T>) {
+        self.start.encode(&t.start);
+        self.end.encode(&t.end);
+    }
+
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a Range<T>> + Clone)
+    where
+        Range<T>: 'a,
+    {
+        self.start.encode_vectored(i.clone().map(|t| &t.start));
+        self.end.encode_vectored(i.map(|t| &t.end));
+    }
+}
+
+// Like serde, only encodes start and end (not whether the range has been exhausted by iteration).
+impl<T: Encode> Encoder<RangeInclusive<T>> for RangeEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, t: &RangeInclusive<T>) {
+        self.start.encode(t.start());
+        self.end.encode(t.end());
+    }
+
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a RangeInclusive<T>> + Clone)
+    where
+        RangeInclusive<T>: 'a,
+    {
+        self.start
+            .encode_vectored(i.clone().map(RangeInclusive::start));
+        self.end.encode_vectored(i.map(RangeInclusive::end));
+    }
+}
+
+impl<T: Encode> Buffer for RangeEncoder<T> {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.start.collect_into(out);
+        self.end.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.start.reserve(additional);
+        self.end.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.start.heap_size() + self.end.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.start.encoded_len() + self.end.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.start.clear();
+        self.end.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.start.shrink_to_fit();
+        self.end.shrink_to_fit();
+    }
+}
+
+pub struct RangeDecoder<'a, T: Decode<'a>> {
+    start: T::Decoder,
+    end: T::Decoder,
+}
+
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>> Default for RangeDecoder<'a, T> {
+    fn default() -> Self {
+        Self {
+            start: Default::default(),
+            end: Default::default(),
+        }
+    }
+}
+
+impl<'a, T: Decode<'a>> View<'a> for RangeDecoder<'a, T> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.start.populate(input, length)?;
+        self.end.populate(input, length)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.start.heap_size() + self.end.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.start.set_recursion_depth(depth);
+        self.end.set_recursion_depth(depth);
+    }
+}
+
+impl<'a, T: Decode<'a>> Decoder<'a, Range<T>> for RangeDecoder<'a, T> {
+    #[inline(always)]
+    fn decode(&mut self) -> Range<T> {
+        let start = self.start.decode();
+        start..self.end.decode()
+    }
+}
+
+impl<'a, T: Decode<'a>> Decoder<'a, RangeInclusive<T>> for RangeDecoder<'a, T> {
+    #[inline(always)]
+    fn decode(&mut self) -> RangeInclusive<T> {
+        let start = self.start.decode();
+        start..=self.end.decode()
+    }
+}
+
+pub struct BoundEncoder<T: Encode> {
+    variants: VariantEncoder<u8, 3>,
+    // Included and Excluded share a column since they're both T.
+    values: T::Encoder,
+}
+
+// Can't derive since it would bound T: Default.
+impl<T: Encode> Default for BoundEncoder<T> {
+    fn default() -> Self {
+        Self {
+            variants: Default::default(),
+            values: Default::default(),
+        }
+    }
+}
+
+impl<T: Encode> Encoder<Bound<T>> for BoundEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, t: &Bound<T>) {
+        let (variant, value) = match t {
+            Bound::Included(v) => (0, Some(v)),
+            Bound::Excluded(v) => (1, Some(v)),
+            Bound::Unbounded => (2, None),
+        };
+        self.variants.encode(&variant);
+        if let Some(v) = value {
+            self.values.reserve(NonZeroUsize::new(1).unwrap());
+            self.values.encode(v);
+        }
+    }
+}
+
+impl<T: Encode> Buffer for BoundEncoder<T> {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.variants.collect_into(out);
+        self.values.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.variants.reserve(additional);
+        // We don't know how many are Unbounded, so we can't reserve more.
+    }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.values.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.variants.encoded_len() + self.values.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.variants.clear();
+        self.values.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.variants.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+}
+
+pub struct BoundDecoder<'a, T: Decode<'a>> {
+    variants: VariantDecoder<'a, u8, 3, 3>,
+    values: T::Decoder,
+}
+
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>> Default for BoundDecoder<'a, T> {
+    fn default() -> Self {
+        Self {
+            variants: Default::default(),
+            values: Default::default(),
+        }
+    }
+}
+
+impl<'a, T: Decode<'a>> View<'a> for BoundDecoder<'a, T> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.variants.populate(input, length)?;
+        let values = self.variants.length(0) + self.variants.length(1);
+        self.values.populate(input, values)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.values.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.values.set_recursion_depth(depth);
+    }
+}
+
+impl<'a, T: Decode<'a>> Decoder<'a, Bound<T>> for BoundDecoder<'a, T> {
+    #[inline(always)]
+    fn decode(&mut self) -> Bound<T> {
+        match self.variants.decode() {
+            0 => Bound::Included(self.values.decode()),
+            1 => Bound::Excluded(self.values.decode()),
+            _ => Bound::Unbounded,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::ops::Bound;
+
+    #[test]
+    fn range() {
+        let v = (1u8..2, String::from("a")..=String::from("b"));
+        assert_eq!(decode::<(_, _)>(&encode(&v)).unwrap(), v);
+
+        let reversed = core::ops::Range {
+            start: u32::MAX,
+            end: 0,
+        };
+        let v = vec![1u32..2, 3..3, reversed];
+        assert_eq!(
+            decode::<Vec<core::ops::Range<u32>>>(&encode(&v)).unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn bound() {
+        let v = vec![
+            Bound::Included(1u16),
+            Bound::Unbounded,
+            Bound::Excluded(2),
+            Bound::Unbounded,
+        ];
+        assert_eq!(decode::<Vec<Bound<u16>>>(&encode(&v)).unwrap(), v);
+
+        // Variant 3 isn't valid.
+        assert!(decode::<Bound<u16>>(&encode(&3u8)).is_err());
+    }
+
+    fn bench_data() -> Vec<core::ops::Range<u32>> {
+        crate::random_data(1000)
+            .into_iter()
+            .map(|(a, b): (u32, u32)| a..b)
+            .collect()
+    }
+    crate::bench_encode_decode!(range_vec: Vec<_>);
+}
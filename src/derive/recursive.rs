@@ -0,0 +1,238 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use crate::output::Output;
+use alloc::boxed::Box;
+use core::mem::MaybeUninit;
+use core::num::NonZeroUsize;
+
+/// Maximum nesting of `#[bitcode(recursive)]` fields while decoding. Deeper input fails to decode
+/// instead of overflowing the stack.
+pub const MAX_RECURSION_DEPTH: usize = 128;
+
+/// Encodes a `#[bitcode(recursive)]` field. The inner encoder is boxed and only created once it's
+/// needed, so the derived encoder isn't infinitely sized.
+pub struct RecursiveEncoder<T: Encode + ?Sized>(Option<Box<T::Encoder>>);
+
+// Can't derive since it would bound T: Default.
+impl<T: Encode + ?Sized> Default for RecursiveEncoder<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T: Encode + ?Sized> RecursiveEncoder<T> {
+    #[inline(always)]
+    fn inner(&mut self) -> &mut T::Encoder {
+        self.0.get_or_insert_with(Default::default)
+    }
+}
+
+impl<T: Encode + ?Sized> Encoder<T> for RecursiveEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, t: &T) {
+        self.inner().encode(t);
+    }
+}
+
+impl<T: Encode + ?Sized> Buffer for RecursiveEncoder<T> {
+    fn collect_into(&mut self, out: &mut Output) {
+        // Encoders which haven't encoded anything don't output anything, so we don't need to create
+        // one (which would recursively create infinitely many).
+        if let Some(inner) = &mut self.0 {
+            inner.collect_into(out);
+        }
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.inner().reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0
+            .as_ref()
+            .map_or(0, |inner| size_of_val(&**inner) + inner.heap_size())
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.as_mut().map_or(0, |inner| inner.encoded_len())
+    }
+
+    fn clear(&mut self) {
+        if let Some(inner) = &mut self.0 {
+            inner.clear();
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        if let Some(inner) = &mut self.0 {
+            inner.shrink_to_fit();
+        }
+    }
+}
+
+/// Decodes a `#[bitcode(recursive)]` field. Like [`RecursiveEncoder`], the inner decoder is boxed
+/// and only created once it's needed. Each box is one level of nesting, so the depth is stored in
+/// the decoder instead of being tracked while populating.
+pub struct RecursiveDecoder<'a, T: Decode<'a>> {
+    inner: Option<Box<T::Decoder>>,
+    depth: usize,
+}
+
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>> Default for RecursiveDecoder<'a, T> {
+    fn default() -> Self {
+        Self {
+            inner: None,
+            depth: 0,
+        }
+    }
+}
+
+impl<'a, T: Decode<'a>> View<'a> for RecursiveDecoder<'a, T> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        // Empty encoders don't output anything (see RecursiveEncoder::collect_into).
+        if length == 0 {
+            return Ok(());
+        }
+        if self.depth >= MAX_RECURSION_DEPTH {
+            return err("recursion limit exceeded");
+        }
+        let depth = self.depth + 1;
+        self.inner
+            .get_or_insert_with(|| {
+                let mut inner = Box::<T::Decoder>::default();
+                inner.set_recursion_depth(depth);
+                inner
+            })
+            .populate(input, length)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.inner
+            .as_ref()
+            .map_or(0, |inner| size_of_val(&**inner) + inner.heap_size())
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.depth = depth;
+        if let Some(inner) = &mut self.inner {
+            inner.set_recursion_depth(depth + 1);
+        }
+    }
+}
+
+impl<'a, T: Decode<'a>> Decoder<'a, T> for RecursiveDecoder<'a, T> {
+    #[inline(always)]
+    fn decode_in_place(&mut self, out: &mut MaybeUninit<T>) {
+        // Safety: decode is only called after populate with length != 0, which creates the decoder.
+        unsafe { self.inner.as_mut().unwrap_unchecked() }.decode_in_place(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, Decode, Encode};
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    enum Expr {
+        Lit(i64),
+        Neg(#[bitcode(recursive)] Box<Expr>),
+        Add(
+            #[bitcode(recursive)] Box<Expr>,
+            #[bitcode(recursive)] Box<Expr>,
+        ),
+    }
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    struct Tree<T> {
+        value: T,
+        #[bitcode(recursive)]
+        children: Vec<Tree<T>>,
+    }
+
+    fn nested(depth: usize) -> Expr {
+        (0..depth).fold(Expr::Lit(1), |e, _| Expr::Neg(Box::new(e)))
+    }
+
+    #[test]
+    fn recursive() {
+        let v = vec![
+            Expr::Lit(1),
+            Expr::Add(
+                Box::new(Expr::Neg(Box::new(Expr::Lit(2)))),
+                Box::new(Expr::Lit(3)),
+            ),
+        ];
+        assert_eq!(decode::<Vec<Expr>>(&encode(&v)).unwrap(), v);
+
+        let tree = Tree {
+            value: String::from("root"),
+            children: vec![
+                Tree {
+                    value: String::from("a"),
+                    children: vec![],
+                },
+                Tree {
+                    value: String::from("b"),
+                    children: vec![Tree {
+                        value: String::from("c"),
+                        children: vec![],
+                    }],
+                },
+            ],
+        };
+        assert_eq!(decode::<Tree<String>>(&encode(&tree)).unwrap(), tree);
+
+        // Recursive fields are encoded the same as non-recursive fields.
+        let leaf = Tree {
+            value: 1u8,
+            children: vec![],
+        };
+        assert_eq!(encode(&leaf), encode(&(1u8, Vec::<()>::new())));
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let v = nested(super::MAX_RECURSION_DEPTH);
+        assert_eq!(decode::<Expr>(&encode(&v)).unwrap(), v);
+        assert!(decode::<Expr>(&encode(&nested(super::MAX_RECURSION_DEPTH + 1))).is_err());
+
+        // The same decoder is reused after errors and successes.
+        let mut buffer = crate::Buffer::new();
+        assert!(buffer
+            .decode::<Expr>(&encode(&nested(super::MAX_RECURSION_DEPTH + 1)))
+            .is_err());
+        assert_eq!(buffer.decode::<Expr>(&encode(&v)).unwrap(), v);
+        assert_eq!(buffer.decode::<Expr>(&encode(&v)).unwrap(), v);
+
+        // Recursive fields nested inside other containers count too.
+        let deep = |depth: usize| {
+            (0..depth).fold(
+                Tree {
+                    value: 0u8,
+                    children: vec![],
+                },
+                |t, _| Tree {
+                    value: 0,
+                    children: vec![t],
+                },
+            )
+        };
+        // The innermost Tree's empty children are also a level of nesting.
+        let v = deep(super::MAX_RECURSION_DEPTH - 1);
+        assert_eq!(decode::<Tree<u8>>(&encode(&v)).unwrap(), v);
+        assert!(decode::<Tree<u8>>(&encode(&deep(super::MAX_RECURSION_DEPTH))).is_err());
+    }
+
+    fn bench_data() -> Vec<Expr> {
+        crate::random_data::<(u8, i64)>(1000)
+            .into_iter()
+            .map(|(n, v)| (0..n % 8).fold(Expr::Lit(v), |e, _| Expr::Neg(Box::new(e))))
+            .collect()
+    }
+    crate::bench_encode_decode!(expr_vec: Vec<Expr>);
+}
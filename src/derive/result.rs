@@ -2,7 +2,7 @@ use crate::coder::{Buffer, Decoder, Encoder, View};
 use crate::derive::variant::{VariantDecoder, VariantEncoder};
 use crate::derive::{Decode, Encode};
 use crate::error::Error;
-use alloc::vec::Vec;
+use crate::output::Output;
 use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
 
@@ -42,7 +42,7 @@ impl<T: Encode, E: Encode> Encoder<Result<T, E>> for ResultEncoder<T, E> {
 }
 
 impl<T: Encode, E: Encode> Buffer for ResultEncoder<T, E> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.variants.collect_into(out);
         self.ok.collect_into(out);
         self.err.collect_into(out);
@@ -52,6 +52,26 @@ impl<T: Encode, E: Encode> Buffer for ResultEncoder<T, E> {
         self.variants.reserve(additional);
         // We don't know how many are Ok or Err, so we can't reserve more.
     }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.ok.heap_size() + self.err.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.variants.encoded_len() + self.ok.encoded_len() + self.err.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.variants.clear();
+        self.ok.clear();
+        self.err.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.variants.shrink_to_fit();
+        self.ok.shrink_to_fit();
+        self.err.shrink_to_fit();
+    }
 }
 
 pub struct ResultDecoder<'a, T: Decode<'a>, E: Decode<'a>> {
@@ -77,6 +97,15 @@ impl<'a, T: Decode<'a>, E: Decode<'a>> View<'a> for ResultDecoder<'a, T, E> {
         self.ok.populate(input, self.variants.length(0))?;
         self.err.populate(input, self.variants.length(1))
     }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + self.ok.heap_size() + self.err.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.ok.set_recursion_depth(depth);
+        self.err.set_recursion_depth(depth);
+    }
 }
 
 impl<'a, T: Decode<'a>, E: Decode<'a>> Decoder<'a, Result<T, E>> for ResultDecoder<'a, T, E> {
@@ -1,6 +1,6 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::derive::{Decode, Encode};
-use alloc::vec::Vec;
+use crate::output::Output;
 use core::num::NonZeroUsize;
 use core::ops::Deref;
 
@@ -21,12 +21,32 @@ impl<D: Deref<Target = T>, T: Encode + ?Sized> Encoder<D> for DerefEncoder<T> {
 }
 
 impl<T: Encode + ?Sized> Buffer for DerefEncoder<T> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.0.collect_into(out);
     }
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional);
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        self.0.collect_into_parallel(out);
+    }
 }
 
 /// Decodes a `T` and then converts it with [`From`]. For example `T` -> `Box<T>` and `Vec<T>` -> `Box<[T]>`.
@@ -43,6 +63,27 @@ impl<'a, T: Decode<'a>> View<'a> for FromDecoder<'a, T> {
     fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
         self.0.populate(input, length)
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.0.set_recursion_depth(depth);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        self.0.populate_parallel(input, length, tasks)
+    }
 }
 
 impl<'a, F: From<T>, T: Decode<'a>> Decoder<'a, F> for FromDecoder<'a, T> {
@@ -50,6 +91,13 @@ impl<'a, F: From<T>, T: Decode<'a>> Decoder<'a, F> for FromDecoder<'a, T> {
     fn decode(&mut self) -> F {
         F::from(self.0.decode())
     }
+
+    const SEEKABLE: bool = <T::Decoder as Decoder<'a, T>>::SEEKABLE;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.0.seek(n);
+    }
 }
 
 #[cfg(test)]
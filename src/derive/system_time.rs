@@ -0,0 +1,64 @@
+use crate::coder::Result;
+use crate::derive::convert::{impl_try_convert, ConvertFrom, TryConvertFrom};
+use crate::error::err;
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// `Ok` is the [`Duration`] since [`UNIX_EPOCH`] and `Err` is the [`Duration`] before it.
+type SystemTimeConversion = core::result::Result<Duration, Duration>;
+
+impl ConvertFrom<&SystemTime> for SystemTimeConversion {
+    #[inline(always)]
+    fn convert_from(value: &SystemTime) -> Self {
+        value.duration_since(UNIX_EPOCH).map_err(|e| e.duration())
+    }
+}
+
+impl TryConvertFrom<SystemTimeConversion> for SystemTime {
+    #[inline(always)]
+    fn try_convert_from(value: SystemTimeConversion) -> Result<Self> {
+        // Not every `Duration` is a valid `SystemTime` on every platform.
+        let time = match value {
+            Ok(after) => UNIX_EPOCH.checked_add(after),
+            Err(before) => UNIX_EPOCH.checked_sub(before),
+        };
+        time.map_or_else(|| err("SystemTime out of range"), Ok)
+    }
+}
+
+impl_try_convert!(SystemTime, SystemTimeConversion);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use core::time::Duration;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn system_time() {
+        let v = vec![
+            SystemTime::now(),
+            UNIX_EPOCH,
+            UNIX_EPOCH + Duration::new(1, 2),
+            UNIX_EPOCH - Duration::new(3, 4),
+        ];
+        assert_eq!(decode::<Vec<SystemTime>>(&encode(&v)).unwrap(), v);
+
+        let since_epoch: Result<Duration, Duration> = Ok(Duration::new(5, 6));
+        assert_eq!(
+            decode::<SystemTime>(&encode(&since_epoch)).unwrap(),
+            UNIX_EPOCH + Duration::new(5, 6)
+        );
+        let too_large: Result<Duration, Duration> = Ok(Duration::MAX);
+        assert!(decode::<SystemTime>(&encode(&too_large)).is_err());
+    }
+
+    fn bench_data() -> Vec<SystemTime> {
+        crate::random_data::<u32>(1000)
+            .into_iter()
+            .map(|s| UNIX_EPOCH + Duration::from_millis(s as u64))
+            .collect()
+    }
+    crate::bench_encode_decode!(system_time_vec: Vec<_>);
+}
@@ -1,8 +1,10 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::fast::{CowSlice, NextUnchecked, PushUnchecked, VecImpl};
-use crate::pack::{check_less_than, pack_bytes_less_than, unpack_bytes_less_than};
-use crate::pack_ints::{pack_ints, unpack_ints, Int};
-use alloc::vec::Vec;
+use crate::output::Output;
+use crate::pack::{
+    check_less_than, pack_bytes_less_than, pack_bytes_less_than_len, unpack_bytes_less_than,
+};
+use crate::pack_ints::{pack_ints, pack_ints_len, unpack_ints, Int};
 use core::any::TypeId;
 use core::num::NonZeroUsize;
 
@@ -17,7 +19,7 @@ impl<T: Int, const N: usize> Encoder<T> for VariantEncoder<T, N> {
 }
 
 impl<T: Int, const N: usize> Buffer for VariantEncoder<T, N> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         assert!(N >= 2);
         if TypeId::of::<T>() != TypeId::of::<u8>() {
             pack_ints(self.0.as_mut_slice(), out);
@@ -30,6 +32,26 @@ impl<T: Int, const N: usize> Buffer for VariantEncoder<T, N> {
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional.get());
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        if TypeId::of::<T>() != TypeId::of::<u8>() {
+            pack_ints_len(self.0.as_mut_slice())
+        } else {
+            pack_bytes_less_than_len::<N>(self.0.len())
+        }
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
 }
 
 pub struct VariantDecoder<'a, T: Int, const N: usize, const HISTOGRAM: usize> {
@@ -76,6 +98,10 @@ impl<'a, T: Int + Into<usize>, const N: usize, const HISTOGRAM: usize> View<'a>
         }
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size()
+    }
 }
 
 impl<'a, T: Int + Into<usize>, const N: usize, const HISTOGRAM: usize> Decoder<'a, T>
@@ -86,6 +112,13 @@ impl<'a, T: Int + Into<usize>, const N: usize, const HISTOGRAM: usize> Decoder<'
     fn decode(&mut self) -> T {
         T::from_unaligned(unsafe { self.variants.mut_slice().next_unchecked() })
     }
+
+    const SEEKABLE: bool = true;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.variants.mut_slice().seek(n);
+    }
 }
 
 #[cfg(test)]
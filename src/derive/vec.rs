@@ -2,12 +2,14 @@ use crate::coder::{Buffer, Decoder, Encoder, Result, View, MAX_VECTORED_CHUNK};
 use crate::derive::{Decode, Encode};
 use crate::fast::Unaligned;
 use crate::length::{LengthDecoder, LengthEncoder};
+use crate::output::Output;
+use alloc::borrow::{Borrow, Cow};
 use alloc::collections::{BTreeSet, BinaryHeap, LinkedList, VecDeque};
 use alloc::vec::Vec;
 use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "indexmap"))]
 use core::hash::{BuildHasher, Hash};
 #[cfg(feature = "std")]
 use std::collections::HashSet;
@@ -31,7 +33,7 @@ impl<T: Encode> Default for VecEncoder<T> {
 }
 
 impl<T: Encode> Buffer for VecEncoder<T> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.lengths.collect_into(out);
         self.elements.collect_into(out);
     }
@@ -40,6 +42,30 @@ impl<T: Encode> Buffer for VecEncoder<T> {
         self.lengths.reserve(additional);
         // We don't know the lengths of the vectors, so we can't reserve more.
     }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size() + self.elements.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.lengths.encoded_len() + self.elements.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.lengths.clear();
+        self.elements.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.lengths.shrink_to_fit();
+        self.elements.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        self.lengths.collect_into(&mut out.bytes());
+        self.elements.collect_into_parallel(out);
+    }
 }
 
 /// Copies `N` or `n` bytes from `src` to `dst` depending on if `src` lies within a memory page.
@@ -142,26 +168,40 @@ impl<T: Encode> VecEncoder<T> {
     }
 }
 
+/// Encodes the elements of `v` (but not its length). Also used by `ext`.
+#[inline(always)]
+pub(crate) fn encode_elements<T: Encode>(elements: &mut T::Encoder, v: &[T]) {
+    let n = v.len();
+    if let Some(primitive) = elements.as_primitive() {
+        primitive.reserve(n);
+        unsafe {
+            let ptr = primitive.end_ptr();
+            v.as_ptr().copy_to_nonoverlapping(ptr, n);
+            primitive.set_end_ptr(ptr.add(n));
+        }
+    } else {
+        encode_elements_chunked(elements, v);
+    }
+}
+
+/// Encodes the elements of `v` (but not its length) with [`Encoder::encode_vectored`]. `v` can also
+/// contain references to the elements (e.g. from [`encode_iter`](crate::encode_iter)).
+#[inline(always)]
+pub(crate) fn encode_elements_chunked<T: Encode, R: Borrow<T>>(elements: &mut T::Encoder, v: &[R]) {
+    if let Some(n) = NonZeroUsize::new(v.len()) {
+        elements.reserve(n);
+        // Uses chunks to keep everything in the CPU cache. TODO pick optimal chunk size.
+        for chunk in v.chunks(MAX_VECTORED_CHUNK) {
+            elements.encode_vectored(chunk.iter().map(Borrow::borrow));
+        }
+    }
+}
+
 impl<T: Encode> Encoder<[T]> for VecEncoder<T> {
     #[inline(always)]
     fn encode(&mut self, v: &[T]) {
-        let n = v.len();
-        self.lengths.encode(&n);
-
-        if let Some(primitive) = self.elements.as_primitive() {
-            primitive.reserve(n);
-            unsafe {
-                let ptr = primitive.end_ptr();
-                v.as_ptr().copy_to_nonoverlapping(ptr, n);
-                primitive.set_end_ptr(ptr.add(n));
-            }
-        } else if let Some(n) = NonZeroUsize::new(n) {
-            self.elements.reserve(n);
-            // Uses chunks to keep everything in the CPU cache. TODO pick optimal chunk size.
-            for chunk in v.chunks(MAX_VECTORED_CHUNK) {
-                self.elements.encode_vectored(chunk.iter());
-            }
-        }
+        self.lengths.encode(&v.len());
+        encode_elements(&mut self.elements, v);
     }
 
     #[inline(always)]
@@ -205,7 +245,7 @@ impl<T: Encode> Encoder<[T]> for VecEncoder<T> {
 }
 
 pub struct VecDecoder<'a, T: Decode<'a>> {
-    // pub(crate) for arrayvec::ArrayVec.
+    // pub(crate) for arrayvec::ArrayVec, heapless::Vec, etc.
     pub(crate) lengths: LengthDecoder<'a>,
     pub(crate) elements: T::Decoder,
 }
@@ -225,6 +265,29 @@ impl<'a, T: Decode<'a>> View<'a> for VecDecoder<'a, T> {
         self.lengths.populate(input, length)?;
         self.elements.populate(input, self.lengths.length())
     }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size() + self.elements.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.elements.set_recursion_depth(depth);
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        self.lengths.populate(input, length)?;
+        self.elements
+            .populate_parallel(input, self.lengths.length(), tasks)
+    }
 }
 
 macro_rules! encode_body {
@@ -269,6 +332,17 @@ macro_rules! decode_body {
         }
     };
 }
+// Reuses the collection's capacity, but not its elements.
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "indexmap"))]
+macro_rules! decode_into_body {
+    ($t:ty) => {
+        #[inline(always)]
+        fn decode_into(&mut self, out: &mut $t) {
+            out.clear();
+            out.extend((0..self.lengths.decode()).map(|_| self.elements.decode()));
+        }
+    };
+}
 
 impl<T: Encode> Encoder<Vec<T>> for VecEncoder<T> {
     #[inline(always)]
@@ -288,6 +362,37 @@ impl<'a, T: Decode<'a>> Decoder<'a, Vec<T>> for VecDecoder<'a, T> {
     #[inline(always)]
     fn decode_in_place(&mut self, out: &mut MaybeUninit<Vec<T>>) {
         let length = self.lengths.decode();
+        self.decode_elements_in_place(length, out);
+    }
+
+    #[inline(always)]
+    fn decode_into(&mut self, out: &mut Vec<T>) {
+        let length = self.lengths.decode();
+        // Primitives don't have allocations to reuse and are faster to copy all at once.
+        let reused = if self.elements.as_primitive().is_some() {
+            0
+        } else {
+            length.min(out.len())
+        };
+        out.truncate(reused);
+        for v in out.iter_mut() {
+            self.elements.decode_into(v);
+        }
+
+        let remaining = length - reused;
+        out.reserve(remaining);
+        self.decode_elements_into(unsafe {
+            out.spare_capacity_mut().get_unchecked_mut(..remaining)
+        });
+        // Safety: decode_elements_into initialized the `remaining` elements after `reused`.
+        unsafe { out.set_len(length) };
+    }
+}
+
+impl<'a, T: Decode<'a>> VecDecoder<'a, T> {
+    /// Decodes `length` elements into a `Vec<T>`. Also used by `Cow<[T]>`.
+    #[inline(always)]
+    fn decode_elements_in_place(&mut self, length: usize, out: &mut MaybeUninit<Vec<T>>) {
         // Fast path, avoid memcpy and mutating len.
         if length == 0 {
             out.write(Vec::new());
@@ -295,23 +400,58 @@ impl<'a, T: Decode<'a>> Decoder<'a, Vec<T>> for VecDecoder<'a, T> {
         }
 
         let v = out.write(Vec::with_capacity(length));
-        if let Some(primitive) = self.elements.as_primitive() {
-            unsafe {
-                primitive
-                    .as_ptr()
-                    .copy_to_nonoverlapping(v.as_mut_ptr() as *mut Unaligned<T>, length);
-                primitive.advance(length);
-            }
-        } else {
-            let spare = v.spare_capacity_mut();
-            for i in 0..length {
-                let out = unsafe { spare.get_unchecked_mut(i) };
-                self.elements.decode_in_place(out);
-            }
-        }
+        self.decode_elements_into(unsafe { v.spare_capacity_mut().get_unchecked_mut(..length) });
         unsafe { v.set_len(length) };
     }
+
+    /// Decodes `out.len()` elements into `out`. Also used by `Rc<[T]>`, `Arc<[T]>` and `ext`.
+    #[inline(always)]
+    pub(crate) fn decode_elements_into(&mut self, out: &mut [MaybeUninit<T>]) {
+        decode_elements_into(&mut self.elements, out);
+    }
+}
+
+/// Decodes `out.len()` elements into `out`. Also used by `ext`.
+#[inline(always)]
+pub(crate) fn decode_elements_into<'a, T: Decode<'a>>(
+    elements: &mut T::Decoder,
+    out: &mut [MaybeUninit<T>],
+) {
+    let length = out.len();
+    if let Some(primitive) = elements.as_primitive() {
+        unsafe {
+            primitive
+                .as_ptr()
+                .copy_to_nonoverlapping(out.as_mut_ptr() as *mut Unaligned<T>, length);
+            primitive.advance(length);
+        }
+    } else {
+        for i in 0..length {
+            let out = unsafe { out.get_unchecked_mut(i) };
+            elements.decode_in_place(out);
+        }
+    }
+}
+
+// Decodes directly into the shared allocation instead of converting from a Vec<T>.
+macro_rules! impl_shared_slice {
+    ($(::$ptr: ident)*) => {
+        impl<'a, T: Decode<'a>> Decoder<'a, $(::$ptr)*<[T]>> for VecDecoder<'a, T> {
+            #[inline(always)]
+            fn decode(&mut self) -> $(::$ptr)*<[T]> {
+                let length = self.lengths.decode();
+                let mut shared = $(::$ptr)*::<[T]>::new_uninit_slice(length);
+                // Can't fail since `shared` was just created.
+                self.decode_elements_into($(::$ptr)*::get_mut(&mut shared).unwrap());
+                // Safety: decode_elements_into initialized all `length` elements.
+                unsafe { shared.assume_init() }
+            }
+        }
+    };
 }
+impl_shared_slice!(::alloc::rc::Rc);
+#[cfg(target_has_atomic = "ptr")]
+impl_shared_slice!(::alloc::sync::Arc);
 
 impl<T: Encode> Encoder<BinaryHeap<T>> for VecEncoder<T> {
     encode_body!(BinaryHeap<T>); // When BinaryHeap::as_slice is stable use [T] impl.
@@ -342,6 +482,31 @@ impl<'a, T: Decode<'a> + Eq + Hash, S: BuildHasher + Default> Decoder<'a, HashSe
     for VecDecoder<'a, T>
 {
     decode_body!(HashSet<T, S>);
+    decode_into_body!(HashSet<T, S>);
+}
+
+#[cfg(feature = "hashbrown")]
+impl<T: Encode, S> Encoder<hashbrown::HashSet<T, S>> for VecEncoder<T> {
+    encode_body_internal_iteration!(hashbrown::HashSet<T, S>);
+}
+#[cfg(feature = "hashbrown")]
+impl<'a, T: Decode<'a> + Eq + Hash, S: BuildHasher + Default> Decoder<'a, hashbrown::HashSet<T, S>>
+    for VecDecoder<'a, T>
+{
+    decode_body!(hashbrown::HashSet<T, S>);
+    decode_into_body!(hashbrown::HashSet<T, S>);
+}
+
+#[cfg(feature = "indexmap")]
+impl<T: Encode, S> Encoder<indexmap::IndexSet<T, S>> for VecEncoder<T> {
+    encode_body!(indexmap::IndexSet<T, S>);
+}
+#[cfg(feature = "indexmap")]
+impl<'a, T: Decode<'a> + Eq + Hash, S: BuildHasher + Default> Decoder<'a, indexmap::IndexSet<T, S>>
+    for VecDecoder<'a, T>
+{
+    decode_body!(indexmap::IndexSet<T, S>);
+    decode_into_body!(indexmap::IndexSet<T, S>);
 }
 
 impl<T: Encode> Encoder<LinkedList<T>> for VecEncoder<T> {
@@ -351,6 +516,34 @@ impl<'a, T: Decode<'a>> Decoder<'a, LinkedList<T>> for VecDecoder<'a, T> {
     decode_body!(LinkedList<T>);
 }
 
+impl<'b, T: Encode + Clone> Encoder<Cow<'b, [T]>> for VecEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, v: &Cow<'b, [T]>) {
+        self.encode(v.as_ref());
+    }
+
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a Cow<'b, [T]>> + Clone)
+    where
+        Cow<'b, [T]>: 'a,
+    {
+        self.encode_vectored(i.map(Cow::as_ref));
+    }
+}
+impl<'a, T: Decode<'a> + Clone> Decoder<'a, Cow<'a, [T]>> for VecDecoder<'a, T> {
+    #[inline(always)]
+    fn decode(&mut self) -> Cow<'a, [T]> {
+        let length = self.lengths.decode();
+        // Safety: `length` elements remain since `self.lengths` was populated with them.
+        if let Some(borrowed) = unsafe { self.elements.decode_borrowed(length) } {
+            return Cow::Borrowed(borrowed);
+        }
+        let mut owned = MaybeUninit::uninit();
+        self.decode_elements_in_place(length, &mut owned);
+        Cow::Owned(unsafe { owned.assume_init() })
+    }
+}
+
 impl<T: Encode> Encoder<VecDeque<T>> for VecEncoder<T> {
     encode_body_internal_iteration!(VecDeque<T>); // Internal iteration is 10x faster.
 }
@@ -380,6 +573,48 @@ mod tests {
     #[cfg(feature = "std")]
     crate::bench_encode_decode!(hash_set: std::collections::HashSet<_>);
 
+    #[test]
+    fn cow_slice() {
+        use crate::{decode, encode};
+        use alloc::borrow::Cow;
+
+        // Unpacked bytes are borrowed.
+        let v: Vec<i8> = (-128..=127).collect();
+        let encoded = encode(&v);
+        let decoded = decode::<Cow<[i8]>>(&encoded).unwrap();
+        assert!(matches!(decoded, Cow::Borrowed(_)));
+        assert_eq!(decoded, v);
+
+        // Larger ints are owned.
+        let v: Vec<Cow<[u32]>> = vec![Cow::Owned(vec![1, 2]), Cow::Borrowed(&[u32::MAX])];
+        let encoded = encode(&v);
+        assert_eq!(encoded, encode(&vec![vec![1u32, 2], vec![u32::MAX]]));
+        let decoded = decode::<Vec<Cow<[u32]>>>(&encoded).unwrap();
+        assert!(decoded.iter().all(|v| matches!(v, Cow::Owned(_))));
+        assert_eq!(decoded, v);
+    }
+
+    #[test]
+    fn shared_slice() {
+        use crate::{decode, encode};
+        use alloc::boxed::Box;
+        use alloc::rc::Rc;
+        use alloc::string::String;
+        use alloc::sync::Arc;
+
+        let v: Vec<Rc<[u32]>> = vec![Rc::from([]), Rc::from([1, u32::MAX])];
+        let encoded = encode(&v);
+        assert_eq!(encoded, encode(&vec![vec![], vec![1u32, u32::MAX]]));
+        assert_eq!(decode::<Vec<Rc<[u32]>>>(&encoded).unwrap(), v);
+
+        let v: Arc<[String]> = Arc::from([String::from("a"), String::from("bc")]);
+        assert_eq!(decode::<Arc<[String]>>(&encode(&v)).unwrap(), v);
+        assert_eq!(
+            decode::<Box<[String]>>(&encode(&v)).unwrap(),
+            v.to_vec().into()
+        );
+    }
+
     // BinaryHeap can't use bench_encode_decode because it doesn't implement PartialEq.
     #[bench]
     fn bench_binary_heap_decode(b: &mut test::Bencher) {
@@ -0,0 +1,156 @@
+use crate::coder::{Buffer, Encoder};
+use crate::derive::convert::ConvertFrom;
+use crate::derive::Encode;
+use crate::output::Output;
+use core::cell::{Cell, RefCell};
+use core::cmp::Reverse;
+use core::num::{NonZeroUsize, Saturating, Wrapping};
+
+// Like [`core::ops::Deref`] but we can implement it ourselves (and it works with lock guards).
+pub(crate) trait Wrapper<T: ?Sized> {
+    fn with_inner<R>(&self, f: impl FnOnce(&T) -> R) -> R;
+}
+
+/// Encodes the `T` inside of a [`Wrapper`] such as `Wrapping<T>` or `RefCell<T>`.
+pub struct WrapperEncoder<T: Encode + ?Sized>(T::Encoder);
+
+// Can't derive since it would bound T: Default.
+impl<T: Encode + ?Sized> Default for WrapperEncoder<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<W: Wrapper<T>, T: Encode + ?Sized> Encoder<W> for WrapperEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, w: &W) {
+        w.with_inner(|t| self.0.encode(t));
+    }
+}
+
+impl<T: Encode + ?Sized> Buffer for WrapperEncoder<T> {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.0.collect_into(out);
+    }
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.0.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
+macro_rules! impl_wrapper {
+    ($wrapper:ident $(: $bound:ident)?, |$w:ident, $f:ident| $with_inner:expr, $new:expr) => {
+        impl<T $(: $bound)?> Wrapper<T> for $wrapper<T> {
+            #[inline(always)]
+            fn with_inner<R>(&self, $f: impl FnOnce(&T) -> R) -> R {
+                let $w = self;
+                $with_inner
+            }
+        }
+        impl<T> ConvertFrom<T> for $wrapper<T> {
+            #[inline(always)]
+            fn convert_from(t: T) -> Self {
+                $new(t)
+            }
+        }
+        impl<T: Encode $(+ $bound)?> Encode for $wrapper<T> {
+            type Encoder = WrapperEncoder<T>;
+            const MAX_ENCODED_LEN: Option<usize> = T::MAX_ENCODED_LEN;
+        }
+        impl<'a, T: crate::derive::Decode<'a>> crate::derive::Decode<'a> for $wrapper<T> {
+            type Decoder = crate::derive::convert::ConvertFromDecoder<'a, T>;
+        }
+    };
+}
+
+impl_wrapper!(Wrapping, |w, f| f(&w.0), Wrapping);
+impl_wrapper!(Saturating, |w, f| f(&w.0), Saturating);
+impl_wrapper!(Reverse, |w, f| f(&w.0), Reverse);
+impl_wrapper!(Cell: Copy, |w, f| f(&w.get()), Cell::new);
+// Panics if the `RefCell` is mutably borrowed (like `RefCell::borrow`).
+impl_wrapper!(RefCell, |w, f| f(&w.borrow()), RefCell::new);
+
+#[cfg(feature = "std")]
+mod with_std {
+    use super::*;
+    use std::sync::{Mutex, PoisonError, RwLock};
+
+    // Like `Debug`, poisoned locks are still encoded. It is your responsibility to avoid deadlocks,
+    // such as by not encoding a `Mutex` that the current thread has locked.
+    impl_wrapper!(
+        Mutex,
+        |w, f| f(&w.lock().unwrap_or_else(PoisonError::into_inner)),
+        Mutex::new
+    );
+    impl_wrapper!(
+        RwLock,
+        |w, f| f(&w.read().unwrap_or_else(PoisonError::into_inner)),
+        RwLock::new
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::cell::{Cell, RefCell};
+    use core::cmp::Reverse;
+    use core::num::{Saturating, Wrapping};
+
+    #[test]
+    fn wrappers() {
+        let v = (
+            Wrapping(1u8),
+            Saturating(-2i16),
+            Reverse(String::from("3")),
+            Cell::new(4u32),
+            RefCell::new(vec![5u64]),
+        );
+        let encoded = encode(&v);
+        assert_eq!(
+            encoded,
+            encode(&(1u8, -2i16, String::from("3"), 4u32, vec![5u64]))
+        );
+        assert_eq!(decode::<(_, _, _, _, _)>(&encoded).unwrap(), v);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn locks() {
+        use std::sync::{Mutex, RwLock};
+        let v = (Mutex::new(String::from("1")), RwLock::new(2u8));
+        let (mutex, rw_lock) = decode::<(Mutex<String>, RwLock<u8>)>(&encode(&v)).unwrap();
+        assert_eq!(mutex.into_inner().unwrap(), "1");
+        assert_eq!(rw_lock.into_inner().unwrap(), 2);
+
+        // Poisoned locks are still encoded.
+        let mutex = Mutex::new(3u8);
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = mutex.lock().unwrap();
+            panic!();
+        });
+        assert!(mutex.is_poisoned());
+        assert_eq!(decode::<u8>(&encode(&mutex)).unwrap(), 3);
+    }
+
+    fn bench_data() -> Vec<Wrapping<u32>> {
+        crate::random_data(1000).into_iter().map(Wrapping).collect()
+    }
+    crate::bench_encode_decode!(wrapping_vec: Vec<_>);
+}
@@ -1,6 +1,7 @@
 #[cfg(debug_assertions)]
 use alloc::borrow::Cow;
 use core::fmt::{Debug, Display, Formatter};
+use core::num::NonZeroUsize;
 
 /// Short version of `Err(error("..."))`.
 pub fn err<T>(msg: &'static str) -> Result<T, Error> {
@@ -10,18 +11,30 @@ pub fn err<T>(msg: &'static str) -> Result<T, Error> {
 /// Creates an error with a message that might be displayed.
 pub fn error(_msg: &'static str) -> Error {
     #[cfg(debug_assertions)]
-    return Error(Cow::Borrowed(_msg));
+    return Error::new(Cow::Borrowed(_msg));
     #[cfg(not(debug_assertions))]
-    Error(())
+    Error::new(())
+}
+
+/// Creates an error for when an output buffer is smaller than the `needed` bytes.
+pub fn buffer_too_small(needed: usize) -> Error {
+    #[cfg(debug_assertions)]
+    let mut e = Error::new(Cow::Owned(alloc::format!(
+        "buffer too small: {needed} bytes needed"
+    )));
+    #[cfg(not(debug_assertions))]
+    let mut e = Error::new(());
+    e.needed_len = NonZeroUsize::new(needed); // An empty output is never too small.
+    e
 }
 
 /// Creates an error from a `T:` [`Display`].
 #[cfg(feature = "serde")]
 pub fn error_from_display(_t: impl Display) -> Error {
     #[cfg(debug_assertions)]
-    return Error(Cow::Owned(alloc::string::ToString::to_string(&_t)));
+    return Error::new(Cow::Owned(alloc::string::ToString::to_string(&_t)));
     #[cfg(not(debug_assertions))]
-    Error(())
+    Error::new(())
 }
 
 #[cfg(debug_assertions)]
@@ -33,13 +46,31 @@ type ErrorImpl = ();
 /// # Debug mode
 /// In debug mode, the error contains a reason.
 /// # Release mode
-/// In release mode, the error is a zero-sized type for efficiency.
+/// In release mode, the error doesn't contain a reason for efficiency.
 #[cfg_attr(test, derive(PartialEq))]
-pub struct Error(ErrorImpl);
+pub struct Error {
+    reason: ErrorImpl,
+    needed_len: Option<NonZeroUsize>,
+}
+impl Error {
+    fn new(reason: ErrorImpl) -> Self {
+        Self {
+            reason,
+            needed_len: None,
+        }
+    }
+
+    /// If encoding failed because the output slice was too small (see
+    /// [`encode_into_slice`](crate::encode_into_slice)), returns the number of bytes needed.
+    /// Available in both debug and release mode.
+    pub fn needed_len(&self) -> Option<usize> {
+        self.needed_len.map(NonZeroUsize::get)
+    }
+}
 impl Debug for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         #[cfg(debug_assertions)]
-        return write!(f, "Error({:?})", self.0);
+        return write!(f, "Error({:?})", self.reason);
         #[cfg(not(debug_assertions))]
         f.write_str("Error(\"bitcode error\")")
     }
@@ -47,7 +78,7 @@ impl Debug for Error {
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         #[cfg(debug_assertions)]
-        return f.write_str(&self.0);
+        return f.write_str(&self.reason);
         #[cfg(not(debug_assertions))]
         f.write_str("bitcode error")
     }
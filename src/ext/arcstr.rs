@@ -0,0 +1,30 @@
+use arcstr::ArcStr;
+
+// Copies directly into the shared allocation instead of converting from a String.
+super::impl_str!(ArcStr, ArcStr::from);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use arcstr::ArcStr;
+
+    #[test]
+    fn arc_str() {
+        let v = vec![ArcStr::from("abc"), ArcStr::from("☺"), ArcStr::new()];
+        let encoded = encode(&v);
+        assert_eq!(encoded, encode(&vec!["abc", "☺", ""]));
+        assert_eq!(decode::<Vec<ArcStr>>(&encoded).unwrap(), v);
+
+        // Shares the UTF-8 validation of String.
+        assert!(decode::<ArcStr>(&encode(&vec![0xFFu8])).is_err());
+    }
+
+    fn bench_data() -> Vec<ArcStr> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| ArcStr::from(" ".repeat(n as usize % 32)))
+            .collect()
+    }
+    crate::bench_encode_decode!(arc_str_vec: Vec<ArcStr>);
+}
@@ -72,6 +72,10 @@ impl<'a, const N: usize> View<'a> for ArrayStringDecoder<'a, N> {
         }
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
 }
 impl<'a, const N: usize> Decoder<'a, ArrayString<N>> for ArrayStringDecoder<'a, N> {
     #[inline(always)]
@@ -149,6 +153,14 @@ impl<'a, T: Decode<'a>, const N: usize> View<'a> for ArrayVecDecoder<'a, T, N> {
         }
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.0.set_recursion_depth(depth);
+    }
 }
 impl<'a, T: Decode<'a>, const N: usize> Decoder<'a, ArrayVec<T, N>> for ArrayVecDecoder<'a, T, N> {
     #[inline(always)]
@@ -0,0 +1,113 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::error::err;
+use crate::int::{IntDecoder, IntEncoder};
+use crate::length::{LengthDecoder, LengthEncoder};
+use crate::output::Output;
+use core::num::NonZeroUsize;
+
+/// Number of `u64` words needed to store `bits` bits.
+#[inline(always)]
+pub fn word_count(bits: usize) -> usize {
+    bits.div_ceil(64)
+}
+
+/// Encodes a bit vector as its length in bits and its raw `u64` words. Bit `i` is stored in bit
+/// `i % 64` of word `i / 64` and the unused bits of the last word must be zero.
+#[derive(Default)]
+pub struct BitsEncoder {
+    lengths: LengthEncoder,
+    words: IntEncoder<u64>,
+}
+
+impl BitsEncoder {
+    /// `words` must yield at least `word_count(bits)` words. The unused bits of the last word are
+    /// cleared, so they can be anything.
+    #[inline(always)]
+    pub fn encode_words(&mut self, bits: usize, mut words: impl Iterator<Item = u64>) {
+        self.lengths.encode(&bits);
+        if let Some(n) = NonZeroUsize::new(word_count(bits)) {
+            self.words.reserve(n);
+            for _ in 1..n.get() {
+                self.words.encode(&words.next().unwrap());
+            }
+            let unused = (n.get() * 64 - bits) as u32;
+            self.words
+                .encode(&(words.next().unwrap() & u64::MAX >> unused));
+        }
+    }
+}
+
+impl Buffer for BitsEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.lengths.collect_into(out);
+        self.words.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.lengths.reserve(additional);
+        // We don't know the lengths, so we can't reserve words.
+    }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size() + self.words.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.lengths.encoded_len() + self.words.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.lengths.clear();
+        self.words.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.lengths.shrink_to_fit();
+        self.words.shrink_to_fit();
+    }
+}
+
+#[derive(Default)]
+pub struct BitsDecoder<'a> {
+    lengths: LengthDecoder<'a>,
+    words: IntDecoder<'a, u64>,
+}
+
+impl<'a> BitsDecoder<'a> {
+    /// Returns the length in bits and a decoder for its `word_count(bits)` words which must all be
+    /// decoded before calling this again.
+    #[inline(always)]
+    pub fn decode_words(&mut self) -> (usize, &mut IntDecoder<'a, u64>) {
+        (self.lengths.decode(), &mut self.words)
+    }
+}
+
+impl<'a> View<'a> for BitsDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.lengths.populate(input, length)?;
+        let mut lengths = self.lengths.borrowed_clone();
+        // Can't overflow since it's <= the sum of the lengths.
+        let words: usize = (0..length).map(|_| word_count(lengths.decode())).sum();
+        self.words.populate(input, words)?;
+
+        // Validate that the unused bits of each last word are zero so there's only 1 valid encoding.
+        let mut lengths = self.lengths.borrowed_clone();
+        let mut words = self.words.borrowed_clone();
+        for _ in 0..length {
+            let bits = lengths.decode();
+            let mut last = 0u64;
+            for _ in 0..word_count(bits) {
+                last = words.decode();
+            }
+            let used = bits % 64;
+            if used != 0 && last >> used != 0 {
+                return err("invalid trailing bits");
+            }
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size() + self.words.heap_size()
+    }
+}
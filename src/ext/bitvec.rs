@@ -0,0 +1,71 @@
+use super::bits::{word_count, BitsDecoder, BitsEncoder};
+use crate::coder::{Decoder, Encoder};
+use crate::derive::{Decode, Encode};
+use bitvec::field::BitField;
+use bitvec::order::Lsb0;
+use bitvec::store::BitStore;
+use bitvec::vec::BitVec;
+
+// Only Lsb0 is supported since its bits are in the same order as the encoded words.
+impl<T: BitStore> Encoder<BitVec<T, Lsb0>> for BitsEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &BitVec<T, Lsb0>) {
+        self.encode_words(t.len(), t.chunks(64).map(|c| c.load_le::<u64>()));
+    }
+}
+impl<T: BitStore> Encode for BitVec<T, Lsb0> {
+    type Encoder = BitsEncoder;
+}
+
+impl<'a, T: BitStore> Decoder<'a, BitVec<T, Lsb0>> for BitsDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> BitVec<T, Lsb0> {
+        let (bits, words) = self.decode_words();
+        let mut v = BitVec::repeat(false, bits);
+        for chunk in v.chunks_mut(64) {
+            chunk.store_le::<u64>(words.decode());
+        }
+        debug_assert_eq!(word_count(bits), v.chunks(64).len());
+        v
+    }
+}
+impl<'a, T: BitStore> Decode<'a> for BitVec<T, Lsb0> {
+    type Decoder = BitsDecoder<'a>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use bitvec::prelude::*;
+
+    #[test]
+    fn bitvec() {
+        let mut big = bitvec![u8, Lsb0; 1; 130];
+        big.set(64, false);
+        let v = (bitvec![u8, Lsb0;], bitvec![u16, Lsb0; 1, 0, 1], big);
+        assert_eq!(decode::<(_, _, _)>(&encode(&v)).unwrap(), v);
+
+        // Same format regardless of the storage type.
+        let v: BitVec = bitvec![1, 0, 1];
+        assert_eq!(encode(&v), encode(&bitvec![u8, Lsb0; 1, 0, 1]));
+        assert_eq!(encode(&v), encode(&(3u8, 0b101u64)));
+
+        // Unused bits aren't encoded.
+        let mut v: BitVec = bitvec![1; 64];
+        v.truncate(3);
+        assert_eq!(encode(&v), encode(&(3u8, 0b111u64)));
+
+        // Unused bits must be zero.
+        assert!(decode::<BitVec>(&encode(&(3u8, 0b1101u64))).is_err());
+        assert!(decode::<BitVec>(&encode(&(64u8, u64::MAX))).is_ok());
+    }
+
+    fn bench_data() -> Vec<BitVec<u64>> {
+        crate::random_data::<(u8, u64)>(1000)
+            .into_iter()
+            .map(|(n, w)| w.view_bits::<Lsb0>()[..n as usize % 65].to_bitvec())
+            .collect()
+    }
+    crate::bench_encode_decode!(bitvec_vec: Vec<BitVec<u64>>);
+}
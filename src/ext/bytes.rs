@@ -0,0 +1,184 @@
+use crate::bytes::{BytesDecoder, BytesEncoder};
+use crate::coder::{Decoder, Encoder, Result, View};
+use crate::derive::{Decode, Encode};
+use bytes::{Bytes, BytesMut};
+
+// Encoded the same way as &[u8].
+impl Encoder<Bytes> for BytesEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &Bytes) {
+        self.encode(&t[..]);
+    }
+}
+impl Encoder<BytesMut> for BytesEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &BytesMut) {
+        self.encode(&t[..]);
+    }
+}
+impl Encode for Bytes {
+    type Encoder = BytesEncoder;
+}
+impl Encode for BytesMut {
+    type Encoder = BytesEncoder;
+}
+
+impl<'a> Decoder<'a, BytesMut> for BytesDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> BytesMut {
+        let v: &[u8] = self.decode();
+        v.into()
+    }
+}
+impl<'a> Decode<'a> for BytesMut {
+    type Decoder = BytesDecoder<'a>;
+}
+
+/// Decodes [`Bytes`] as slices of the input if it's being decoded by [`decode_bytes`] or copies
+/// them otherwise.
+#[derive(Default)]
+pub struct SharedBytesDecoder<'a> {
+    bytes: BytesDecoder<'a>,
+    // The input being decoded (if it's a `Bytes`). Dropped after the last value is decoded so a
+    // reused decoder doesn't keep it alive.
+    source: Option<Bytes>,
+    remaining: usize,
+}
+
+impl<'a> View<'a> for SharedBytesDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.source = None;
+        self.remaining = length;
+        #[cfg(feature = "std")]
+        if length != 0 {
+            let input = input.as_ptr_range();
+            self.source = SOURCE.with(|source| {
+                source.borrow().as_ref().and_then(|source| {
+                    let range = source.as_ptr_range();
+                    (range.start <= input.start && input.end <= range.end).then(|| source.clone())
+                })
+            });
+        }
+        self.bytes.populate(input, length)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.bytes.heap_size()
+    }
+}
+
+impl<'a> Decoder<'a, Bytes> for SharedBytesDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> Bytes {
+        let v: &[u8] = self.bytes.decode();
+        self.remaining -= 1;
+        let source = if self.remaining == 0 {
+            self.source.take()
+        } else {
+            self.source.clone()
+        };
+        if let Some(source) = source {
+            source.slice_ref(v)
+        } else {
+            Bytes::copy_from_slice(v)
+        }
+    }
+}
+
+impl<'a> Decode<'a> for Bytes {
+    type Decoder = SharedBytesDecoder<'a>;
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The [`Bytes`] currently being decoded by [`decode_bytes`] on this thread.
+    static SOURCE: core::cell::RefCell<Option<Bytes>> = const { core::cell::RefCell::new(None) };
+}
+
+/// Like [`decode`](crate::decode) but decodes [`Bytes`] as slices of `bytes` instead of copying
+/// them.
+///
+/// **Warning:** The format is subject to change between major versions.
+#[cfg(feature = "std")]
+pub fn decode_bytes<T: crate::DecodeOwned>(bytes: Bytes) -> core::result::Result<T, crate::Error> {
+    // Restores the previous source even if decoding panics (or calls decode_bytes recursively).
+    struct Restore(Option<Bytes>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SOURCE.with(|source| *source.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = Restore(SOURCE.with(|source| source.borrow_mut().replace(bytes.clone())));
+    crate::decode(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use bytes::{Bytes, BytesMut};
+
+    #[test]
+    fn bytes() {
+        let v = (
+            Bytes::from_static(b"abc"),
+            BytesMut::from(&[1u8, 2, 255][..]),
+        );
+        let encoded = encode(&v);
+        assert_eq!(
+            encoded,
+            encode(&(b"abc".as_slice(), [1u8, 2, 255].as_slice()))
+        );
+        assert_eq!(decode::<(Bytes, BytesMut)>(&encoded).unwrap(), v);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn decode_bytes() {
+        use super::decode_bytes;
+        let v = vec![
+            Bytes::from_static(b"abc"),
+            Bytes::new(),
+            Bytes::from(vec![255; 100]),
+        ];
+        let encoded = Bytes::from(encode(&v));
+        let decoded: Vec<Bytes> = decode_bytes(encoded.clone()).unwrap();
+        assert_eq!(decoded, v);
+        let range = encoded.as_ptr_range();
+        assert!(decoded
+            .iter()
+            .all(|b| b.is_empty() || range.contains(&b.as_ptr())));
+
+        // decode doesn't borrow.
+        let copied: Vec<Bytes> = decode(&encoded).unwrap();
+        assert_eq!(copied, v);
+        assert!(copied.iter().all(|b| !range.contains(&b.as_ptr())));
+
+        // Errors and nested types still work.
+        assert!(decode_bytes::<Vec<Bytes>>(encoded.slice(..encoded.len() - 1)).is_err());
+        let encoded = Bytes::from(encode(&(1u8, Some(Bytes::from_static(b"d")))));
+        let (_, decoded) = decode_bytes::<(u8, Option<Bytes>)>(encoded.clone()).unwrap();
+        assert!(encoded.as_ptr_range().contains(&decoded.unwrap().as_ptr()));
+
+        // A reused decoder drops the source after decoding the last value.
+        use crate::coder::{Decoder, View};
+        let encoded = Bytes::from(encode(&v));
+        super::SOURCE.with(|s| *s.borrow_mut() = Some(encoded.clone()));
+        let mut decoder = <Vec<Bytes> as crate::Decode>::Decoder::default();
+        decoder.populate(&mut &encoded[..], 1).unwrap();
+        super::SOURCE.with(|s| *s.borrow_mut() = None);
+        assert!(decoder.elements.source.is_some());
+        let decoded: Vec<Bytes> = decoder.decode();
+        assert_eq!(decoded, v);
+        assert!(encoded.as_ptr_range().contains(&decoded[0].as_ptr()));
+        assert!(decoder.elements.source.is_none());
+    }
+
+    fn bench_data() -> Vec<Bytes> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| Bytes::from(vec![n; n as usize / 4]))
+            .collect()
+    }
+    crate::bench_encode_decode!(shared_bytes_vec: Vec<Bytes>);
+}
@@ -0,0 +1,233 @@
+use crate::coder::Result;
+use crate::convert::{impl_convert, impl_try_convert, ConvertFrom, TryConvertFrom};
+use crate::error::err;
+use crate::int::ranged_int;
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc,
+};
+
+ranged_int!(OffsetSeconds, i32, -86_399, 86_399);
+
+// Days since January 1, 1 CE (the proleptic Gregorian calendar).
+impl_try_convert!(NaiveDate, i32);
+
+impl ConvertFrom<&NaiveDate> for i32 {
+    #[inline(always)]
+    fn convert_from(value: &NaiveDate) -> Self {
+        value.num_days_from_ce()
+    }
+}
+
+impl TryConvertFrom<i32> for NaiveDate {
+    #[inline(always)]
+    fn try_convert_from(value: i32) -> Result<Self> {
+        NaiveDate::from_num_days_from_ce_opt(value).map_or_else(|| err("invalid NaiveDate"), Ok)
+    }
+}
+
+// Seconds since midnight and nanoseconds (which are >= 1_000_000_000 during leap seconds).
+type SecondsNanos = (u32, u32);
+impl_try_convert!(NaiveTime, SecondsNanos);
+
+impl ConvertFrom<&NaiveTime> for SecondsNanos {
+    #[inline(always)]
+    fn convert_from(value: &NaiveTime) -> Self {
+        (value.num_seconds_from_midnight(), value.nanosecond())
+    }
+}
+
+impl TryConvertFrom<SecondsNanos> for NaiveTime {
+    #[inline(always)]
+    fn try_convert_from((secs, nanos): SecondsNanos) -> Result<Self> {
+        NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+            .map_or_else(|| err("invalid NaiveTime"), Ok)
+    }
+}
+
+impl_convert!(NaiveDateTime, (NaiveDate, NaiveTime));
+
+impl ConvertFrom<&NaiveDateTime> for (NaiveDate, NaiveTime) {
+    #[inline(always)]
+    fn convert_from(value: &NaiveDateTime) -> Self {
+        (value.date(), value.time())
+    }
+}
+
+impl ConvertFrom<(NaiveDate, NaiveTime)> for NaiveDateTime {
+    #[inline(always)]
+    fn convert_from((date, time): (NaiveDate, NaiveTime)) -> Self {
+        NaiveDateTime::new(date, time)
+    }
+}
+
+// Seconds since the unix epoch and nanoseconds (which are >= 1_000_000_000 during leap seconds).
+type Timestamp = (i64, u32);
+impl_try_convert!(DateTime<Utc>, Timestamp);
+
+impl ConvertFrom<&DateTime<Utc>> for Timestamp {
+    #[inline(always)]
+    fn convert_from(value: &DateTime<Utc>) -> Self {
+        (value.timestamp(), value.timestamp_subsec_nanos())
+    }
+}
+
+impl TryConvertFrom<Timestamp> for DateTime<Utc> {
+    #[inline(always)]
+    fn try_convert_from((secs, nanos): Timestamp) -> Result<Self> {
+        DateTime::from_timestamp(secs, nanos).map_or_else(|| err("invalid DateTime"), Ok)
+    }
+}
+
+impl_convert!(FixedOffset, i32, OffsetSeconds);
+
+impl ConvertFrom<&FixedOffset> for i32 {
+    #[inline(always)]
+    fn convert_from(value: &FixedOffset) -> Self {
+        value.local_minus_utc()
+    }
+}
+
+impl ConvertFrom<OffsetSeconds> for FixedOffset {
+    #[inline(always)]
+    fn convert_from(value: OffsetSeconds) -> Self {
+        FixedOffset::east_opt(value.into_inner()).unwrap()
+    }
+}
+
+// Encodes the UTC timestamp (so it packs well) and the offset.
+impl_convert!(DateTime<FixedOffset>, (DateTime<Utc>, FixedOffset));
+
+impl ConvertFrom<&DateTime<FixedOffset>> for (DateTime<Utc>, FixedOffset) {
+    #[inline(always)]
+    fn convert_from(value: &DateTime<FixedOffset>) -> Self {
+        (value.to_utc(), *value.offset())
+    }
+}
+
+impl ConvertFrom<(DateTime<Utc>, FixedOffset)> for DateTime<FixedOffset> {
+    #[inline(always)]
+    fn convert_from((utc, offset): (DateTime<Utc>, FixedOffset)) -> Self {
+        utc.with_timezone(&offset)
+    }
+}
+
+// Seconds (rounded towards negative infinity) and nanoseconds (always positive).
+type DeltaConversion = (i64, u32);
+impl_try_convert!(TimeDelta, DeltaConversion);
+
+impl ConvertFrom<&TimeDelta> for DeltaConversion {
+    #[inline(always)]
+    fn convert_from(value: &TimeDelta) -> Self {
+        let (secs, nanos) = (value.num_seconds(), value.subsec_nanos());
+        if nanos < 0 {
+            (secs - 1, (nanos + 1_000_000_000) as u32)
+        } else {
+            (secs, nanos as u32)
+        }
+    }
+}
+
+impl TryConvertFrom<DeltaConversion> for TimeDelta {
+    #[inline(always)]
+    fn try_convert_from((secs, nanos): DeltaConversion) -> Result<Self> {
+        TimeDelta::new(secs, nanos).map_or_else(|| err("invalid TimeDelta"), Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc};
+
+    #[test]
+    fn date_time() {
+        let dates = [
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        ];
+        for d in dates {
+            assert_eq!(decode::<NaiveDate>(&encode(&d)).unwrap(), d);
+        }
+        assert!(decode::<NaiveDate>(&encode(&i32::MAX)).is_err());
+
+        let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_999_999_999).unwrap();
+        let times = [
+            NaiveTime::MIN,
+            leap,
+            NaiveTime::from_hms_opt(1, 2, 3).unwrap(),
+        ];
+        for t in times {
+            assert_eq!(decode::<NaiveTime>(&encode(&t)).unwrap(), t);
+        }
+        assert!(decode::<NaiveTime>(&encode(&(86_400u32, 0u32))).is_err());
+        // Leap seconds can only be the last second of a minute.
+        assert!(decode::<NaiveTime>(&encode(&(59u32, 1_000_000_000u32))).is_ok());
+        assert!(decode::<NaiveTime>(&encode(&(58u32, 1_000_000_000u32))).is_err());
+
+        let v = vec![
+            NaiveDateTime::MIN,
+            NaiveDateTime::MAX,
+            dates[2].and_time(leap),
+        ];
+        assert_eq!(decode::<Vec<NaiveDateTime>>(&encode(&v)).unwrap(), v);
+    }
+
+    #[test]
+    fn timestamp() {
+        let utc = vec![
+            DateTime::<Utc>::MIN_UTC,
+            DateTime::<Utc>::MAX_UTC,
+            DateTime::UNIX_EPOCH,
+            DateTime::from_timestamp(-1, 5).unwrap(),
+        ];
+        let encoded = encode(&utc);
+        assert_eq!(decode::<Vec<DateTime<Utc>>>(&encoded).unwrap(), utc);
+        // Encoded as timestamps.
+        assert_eq!(
+            encode(&DateTime::from_timestamp(1, 2).unwrap()),
+            encode(&(1i64, 2u32))
+        );
+        assert!(decode::<DateTime<Utc>>(&encode(&(i64::MAX, 0u32))).is_err());
+        assert!(decode::<DateTime<Utc>>(&encode(&(0i64, 2_000_000_000u32))).is_err());
+
+        let offsets = [
+            FixedOffset::east_opt(86_399).unwrap(),
+            FixedOffset::west_opt(86_399).unwrap(),
+        ];
+        let fixed: Vec<DateTime<FixedOffset>> = utc
+            .iter()
+            .flat_map(|t| offsets.map(|o| t.with_timezone(&o)))
+            .collect();
+        assert_eq!(
+            decode::<Vec<DateTime<FixedOffset>>>(&encode(&fixed)).unwrap(),
+            fixed
+        );
+        assert!(decode::<FixedOffset>(&encode(&86_400i32)).is_err());
+    }
+
+    #[test]
+    fn time_delta() {
+        let v = vec![
+            TimeDelta::MIN,
+            TimeDelta::MAX,
+            TimeDelta::zero(),
+            TimeDelta::new(-1, 1).unwrap(),
+            TimeDelta::nanoseconds(-1),
+        ];
+        assert_eq!(decode::<Vec<TimeDelta>>(&encode(&v)).unwrap(), v);
+        assert!(decode::<TimeDelta>(&encode(&(i64::MAX, 0u32))).is_err());
+        assert!(decode::<TimeDelta>(&encode(&(0i64, 1_000_000_000u32))).is_err());
+    }
+
+    fn bench_data() -> Vec<DateTime<Utc>> {
+        crate::random_data(1000)
+            .into_iter()
+            .map(|(s, n): (u32, u32)| {
+                DateTime::from_timestamp(s as i64, n % 1_000_000_000).unwrap()
+            })
+            .collect()
+    }
+    crate::bench_encode_decode!(date_time_vec: Vec<DateTime<Utc>>);
+}
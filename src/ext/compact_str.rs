@@ -0,0 +1,45 @@
+use compact_str::CompactString;
+
+// Strings up to 24 bytes are decoded inline without allocating.
+super::impl_str!(CompactString, CompactString::new);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use compact_str::CompactString;
+
+    #[test]
+    fn compact_str() {
+        let v = vec![
+            CompactString::new("abc"),
+            CompactString::new("☺"),
+            CompactString::new(""),
+            CompactString::new("a string that is too long to be inline"),
+        ];
+        let encoded = encode(&v);
+        assert_eq!(
+            encoded,
+            encode(&vec![
+                "abc",
+                "☺",
+                "",
+                "a string that is too long to be inline"
+            ])
+        );
+        let decoded = decode::<Vec<CompactString>>(&encoded).unwrap();
+        assert_eq!(decoded, v);
+        assert!(!decoded[0].is_heap_allocated());
+
+        // Shares the UTF-8 validation of String.
+        assert!(decode::<CompactString>(&encode(&vec![0xFFu8])).is_err());
+    }
+
+    fn bench_data() -> Vec<CompactString> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| CompactString::new(" ".repeat(n as usize % 32)))
+            .collect()
+    }
+    crate::bench_encode_decode!(compact_str_vec: Vec<CompactString>);
+}
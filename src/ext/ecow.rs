@@ -0,0 +1,43 @@
+use ecow::EcoString;
+
+// Strings up to 15 bytes are decoded inline without allocating.
+super::impl_str!(EcoString, EcoString::from);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use ecow::EcoString;
+
+    #[test]
+    fn eco_string() {
+        let v = vec![
+            EcoString::from("abc"),
+            EcoString::from("☺"),
+            EcoString::new(),
+            EcoString::from("a string that is too long to be inline"),
+        ];
+        let encoded = encode(&v);
+        assert_eq!(
+            encoded,
+            encode(&vec![
+                "abc",
+                "☺",
+                "",
+                "a string that is too long to be inline"
+            ])
+        );
+        assert_eq!(decode::<Vec<EcoString>>(&encoded).unwrap(), v);
+
+        // Shares the UTF-8 validation of String.
+        assert!(decode::<EcoString>(&encode(&vec![0xFFu8])).is_err());
+    }
+
+    fn bench_data() -> Vec<EcoString> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| EcoString::from(" ".repeat(n as usize % 32)))
+            .collect()
+    }
+    crate::bench_encode_decode!(eco_string_vec: Vec<EcoString>);
+}
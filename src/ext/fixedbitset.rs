@@ -0,0 +1,88 @@
+use super::bits::{BitsDecoder, BitsEncoder};
+use crate::coder::{Decoder, Encoder};
+use crate::derive::{Decode, Encode};
+use fixedbitset::{Block, FixedBitSet};
+
+const BLOCKS_PER_WORD: usize = 64 / Block::BITS as usize;
+
+impl Encoder<FixedBitSet> for BitsEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &FixedBitSet) {
+        let words = t.as_slice().chunks(BLOCKS_PER_WORD).map(|blocks| {
+            blocks
+                .iter()
+                .enumerate()
+                .fold(0, |w, (i, &b)| w | (b as u64) << (i as u32 * Block::BITS))
+        });
+        self.encode_words(t.len(), words);
+    }
+}
+impl Encode for FixedBitSet {
+    type Encoder = BitsEncoder;
+}
+
+impl<'a> Decoder<'a, FixedBitSet> for BitsDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> FixedBitSet {
+        let (bits, words) = self.decode_words();
+        let mut set = FixedBitSet::with_capacity(bits);
+        for blocks in set.as_mut_slice().chunks_mut(BLOCKS_PER_WORD) {
+            let w: u64 = words.decode();
+            for (i, b) in blocks.iter_mut().enumerate() {
+                *b = (w >> (i as u32 * Block::BITS)) as Block;
+            }
+        }
+        set
+    }
+}
+impl<'a> Decode<'a> for FixedBitSet {
+    type Decoder = BitsDecoder<'a>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use fixedbitset::FixedBitSet;
+
+    #[test]
+    fn fixedbitset() {
+        let mut big = FixedBitSet::with_capacity(130);
+        big.insert_range(3..129);
+        let v = vec![FixedBitSet::new(), FixedBitSet::with_capacity(3), big];
+        assert_eq!(decode::<Vec<FixedBitSet>>(&encode(&v)).unwrap(), v);
+
+        let mut v = FixedBitSet::with_capacity(3);
+        v.insert(0);
+        v.insert(2);
+        assert_eq!(encode(&v), encode(&(3u8, 0b101u64)));
+
+        // Unused bits must be zero.
+        assert!(decode::<FixedBitSet>(&encode(&(3u8, 0b1101u64))).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "bitvec")]
+    fn same_format_as_bitvec() {
+        let v: bitvec::vec::BitVec = (0..100).map(|i| i % 3 == 0).collect();
+        let set: FixedBitSet = decode(&encode(&v)).unwrap();
+        assert_eq!(
+            set.ones().collect::<Vec<_>>(),
+            v.iter_ones().collect::<Vec<_>>()
+        );
+        assert_eq!(encode(&set), encode(&v));
+    }
+
+    fn bench_data() -> Vec<FixedBitSet> {
+        crate::random_data::<(u8, u64)>(1000)
+            .into_iter()
+            .map(|(n, w)| {
+                let n = n as usize % 65;
+                let mut set = FixedBitSet::with_capacity(n);
+                set.extend((0..n).filter(|&i| w >> i & 1 != 0));
+                set
+            })
+            .collect()
+    }
+    crate::bench_encode_decode!(fixedbitset_vec: Vec<FixedBitSet>);
+}
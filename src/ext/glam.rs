@@ -1,5 +1,4 @@
 use super::impl_struct;
-use alloc::vec::Vec;
 use glam::*;
 
 trait Affine3AExt {
@@ -0,0 +1,47 @@
+use crate::derive::map::{MapDecoder, MapEncoder};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{HashMap, HashSet};
+
+// Encoded the same way as std's HashMap and HashSet (see map.rs and vec.rs).
+impl<T: Encode, S> Encode for HashSet<T, S> {
+    type Encoder = VecEncoder<T>;
+}
+impl<'a, T: Decode<'a> + Eq + Hash, S: BuildHasher + Default> Decode<'a> for HashSet<T, S> {
+    type Decoder = VecDecoder<'a, T>;
+}
+impl<K: Encode, V: Encode, S> Encode for HashMap<K, V, S> {
+    type Encoder = MapEncoder<K, V>;
+}
+impl<'a, K: Decode<'a> + Eq + Hash, V: Decode<'a>, S: BuildHasher + Default> Decode<'a>
+    for HashMap<K, V, S>
+{
+    type Decoder = MapDecoder<'a, K, V>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::collections::BTreeMap;
+    use alloc::string::String;
+    use hashbrown::{HashMap, HashSet};
+
+    #[test]
+    fn hashbrown() {
+        let map: HashMap<u8, String> = (0..10).map(|i| (i, format!("{i}"))).collect();
+        let encoded = encode(&map);
+        assert_eq!(decode::<HashMap<u8, String>>(&encoded).unwrap(), map);
+        // Any map can decode it.
+        let btree: BTreeMap<u8, String> = map.clone().into_iter().collect();
+        assert_eq!(decode::<BTreeMap<u8, String>>(&encoded).unwrap(), btree);
+
+        let set: HashSet<u8> = map.into_keys().collect();
+        assert_eq!(decode::<HashSet<u8>>(&encode(&set)).unwrap(), set);
+    }
+
+    fn bench_data() -> HashMap<u8, u8> {
+        (0..=255).map(|k| (k, 0)).collect()
+    }
+    crate::bench_encode_decode!(hashbrown_map: HashMap<_, _>);
+}
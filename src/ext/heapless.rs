@@ -0,0 +1,156 @@
+use crate::coder::{Decoder, Encoder, Result, View};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use crate::str::{StrDecoder, StrEncoder};
+use core::mem::MaybeUninit;
+use heapless::{LenType, String, Vec};
+
+impl<T: Encode, const N: usize, L: LenType> Encoder<Vec<T, N, L>> for VecEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, t: &Vec<T, N, L>) {
+        self.encode(t.as_slice());
+    }
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a Vec<T, N, L>> + Clone)
+    where
+        Vec<T, N, L>: 'a,
+    {
+        self.encode_vectored(i.map(Vec::as_slice));
+    }
+}
+impl<T: Encode, const N: usize, L: LenType> Encode for Vec<T, N, L> {
+    type Encoder = VecEncoder<T>;
+}
+
+pub struct HeaplessVecDecoder<'a, T: Decode<'a>, const N: usize>(VecDecoder<'a, T>);
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>, const N: usize> Default for HeaplessVecDecoder<'a, T, N> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+impl<'a, T: Decode<'a>, const N: usize> View<'a> for HeaplessVecDecoder<'a, T, N> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.0.populate(input, length)?;
+        // Safety: `length` was same length passed to populate.
+        if unsafe { self.0.lengths.any_greater_than::<N>(length) } {
+            return err("invalid heapless::Vec");
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.0.set_recursion_depth(depth);
+    }
+}
+impl<'a, T: Decode<'a>, const N: usize, L: LenType> Decoder<'a, Vec<T, N, L>>
+    for HeaplessVecDecoder<'a, T, N>
+{
+    #[inline(always)]
+    fn decode_in_place(&mut self, out: &mut MaybeUninit<Vec<T, N, L>>) {
+        let v = out.write(Vec::new());
+        let n = self.0.lengths.decode();
+        // Safety: We've ensured self.lengths.max_len() <= N in populate and the elements are
+        // initialized before set_len.
+        unsafe {
+            let spare = core::slice::from_raw_parts_mut(v.as_mut_ptr() as *mut MaybeUninit<T>, n);
+            self.0.decode_elements_into(spare);
+            v.set_len(n);
+        }
+    }
+}
+impl<'a, T: Decode<'a>, const N: usize, L: LenType> Decode<'a> for Vec<T, N, L> {
+    type Decoder = HeaplessVecDecoder<'a, T, N>;
+}
+
+impl<const N: usize, L: LenType> Encoder<String<N, L>> for StrEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &String<N, L>) {
+        self.encode(t.as_str());
+    }
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a String<N, L>> + Clone)
+    where
+        String<N, L>: 'a,
+    {
+        self.encode_vectored(i.map(String::as_str));
+    }
+}
+impl<const N: usize, L: LenType> Encode for String<N, L> {
+    type Encoder = StrEncoder;
+}
+
+#[derive(Default)]
+pub struct HeaplessStringDecoder<'a, const N: usize>(StrDecoder<'a>);
+impl<'a, const N: usize> View<'a> for HeaplessStringDecoder<'a, N> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.0.populate(input, length)?;
+        // Safety: `length` was same length passed to populate.
+        if unsafe { self.0.lengths.any_greater_than::<N>(length) } {
+            return err("invalid heapless::String");
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+impl<'a, const N: usize, L: LenType> Decoder<'a, String<N, L>> for HeaplessStringDecoder<'a, N> {
+    #[inline(always)]
+    fn decode_in_place(&mut self, out: &mut MaybeUninit<String<N, L>>) {
+        let s: &str = self.0.decode();
+        let string = out.write(String::new());
+        // Safety: We've ensured `self.lengths.max_len() <= N` in populate.
+        unsafe { string.push_str(s).unwrap_unchecked() };
+    }
+}
+impl<'a, const N: usize, L: LenType> Decode<'a> for String<N, L> {
+    type Decoder = HeaplessStringDecoder<'a, N>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use heapless::{String, Vec};
+
+    #[test]
+    fn heapless_vec() {
+        let v = Vec::<u8, 2>::from_slice(&[0, 1]).unwrap();
+        let b = encode(&v);
+        assert_eq!(b, encode(&vec![0u8, 1]));
+        assert!(decode::<Vec<u8, 1>>(&b).is_err());
+        assert_eq!(decode::<Vec<u8, 2>>(&b).unwrap(), v);
+        assert_eq!(decode::<Vec<u8, 3, u8>>(&b).unwrap(), v);
+        assert!(decode::<Vec<u8, 0>>(&encode(&Vec::<u8, 0>::new())).is_ok());
+
+        let v: Vec<alloc::string::String, 2> = Vec::from_slice(&["a".into()]).unwrap();
+        assert_eq!(
+            decode::<Vec<alloc::string::String, 2>>(&encode(&v)).unwrap(),
+            v
+        );
+    }
+
+    #[test]
+    fn heapless_string() {
+        let v = String::<2>::try_from("01").unwrap();
+        let b = encode(&v);
+        assert_eq!(b, encode("01"));
+        assert!(decode::<String<1>>(&b).is_err());
+        assert_eq!(decode::<String<2>>(&b).unwrap(), v);
+        assert_eq!(decode::<String<3, u8>>(&b).unwrap(), v);
+    }
+
+    fn bench_data() -> alloc::vec::Vec<Vec<u8, 16>> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| (0..n % 16).collect())
+            .collect()
+    }
+    crate::bench_encode_decode!(heapless_vec_vec: alloc::vec::Vec<Vec<u8, 16>>);
+}
@@ -0,0 +1,50 @@
+use crate::derive::map::{MapDecoder, MapEncoder};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use core::hash::{BuildHasher, Hash};
+use indexmap::{IndexMap, IndexSet};
+
+// Encoded in insertion order, which is preserved when decoding.
+impl<T: Encode, S> Encode for IndexSet<T, S> {
+    type Encoder = VecEncoder<T>;
+}
+impl<'a, T: Decode<'a> + Eq + Hash, S: BuildHasher + Default> Decode<'a> for IndexSet<T, S> {
+    type Decoder = VecDecoder<'a, T>;
+}
+impl<K: Encode, V: Encode, S> Encode for IndexMap<K, V, S> {
+    type Encoder = MapEncoder<K, V>;
+}
+impl<'a, K: Decode<'a> + Eq + Hash, V: Decode<'a>, S: BuildHasher + Default> Decode<'a>
+    for IndexMap<K, V, S>
+{
+    type Decoder = MapDecoder<'a, K, V>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use indexmap::{IndexMap, IndexSet};
+
+    #[test]
+    fn index_map() {
+        let map: IndexMap<u8, String> = [(3, "c"), (1, "a"), (2, "b")]
+            .map(|(k, v)| (k, String::from(v)))
+            .into();
+        let encoded = encode(&map);
+        // Encoded the same way as a Vec of key value pairs.
+        assert_eq!(encoded, encode(&vec![(3u8, "c"), (1, "a"), (2, "b")]));
+        let decoded: IndexMap<u8, String> = decode(&encoded).unwrap();
+        assert!(decoded.iter().eq(map.iter()));
+
+        let set: IndexSet<u8> = map.into_keys().collect();
+        let decoded: IndexSet<u8> = decode(&encode(&set)).unwrap();
+        assert_eq!(decoded.into_iter().collect::<Vec<_>>(), [3, 1, 2]);
+    }
+
+    fn bench_data() -> IndexMap<u8, u8> {
+        (0..=255).rev().map(|k| (k, 0)).collect()
+    }
+    crate::bench_encode_decode!(index_map: IndexMap<_, _>);
+}
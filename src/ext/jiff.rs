@@ -0,0 +1,188 @@
+use crate::coder::Result;
+use crate::convert::{impl_convert, impl_try_convert, ConvertFrom, TryConvertFrom};
+use crate::error::err;
+use crate::int::ranged_int;
+use jiff::civil::{Date, DateTime, Time};
+use jiff::{SignedDuration, Timestamp};
+
+ranged_int!(Hour, i8, 0, 23);
+ranged_int!(Minute, i8, 0, 59);
+ranged_int!(Second, i8, 0, 59);
+ranged_int!(Nanosecond, i32, 0, 999_999_999);
+
+// Seconds and nanoseconds since the unix epoch (with the same sign).
+type SecondsNanos = (i64, i32);
+impl_try_convert!(Timestamp, SecondsNanos);
+
+impl ConvertFrom<&Timestamp> for SecondsNanos {
+    #[inline(always)]
+    fn convert_from(value: &Timestamp) -> Self {
+        (value.as_second(), value.subsec_nanosecond())
+    }
+}
+
+impl TryConvertFrom<SecondsNanos> for Timestamp {
+    #[inline(always)]
+    fn try_convert_from((secs, nanos): SecondsNanos) -> Result<Self> {
+        if !same_sign(secs, nanos) {
+            return err("invalid Timestamp");
+        }
+        Timestamp::new(secs, nanos).or_else(|_| err("invalid Timestamp"))
+    }
+}
+
+type DateConversion = (i16, i8, i8);
+impl_try_convert!(Date, DateConversion);
+
+impl ConvertFrom<&Date> for DateConversion {
+    #[inline(always)]
+    fn convert_from(value: &Date) -> Self {
+        (value.year(), value.month(), value.day())
+    }
+}
+
+impl TryConvertFrom<DateConversion> for Date {
+    #[inline(always)]
+    fn try_convert_from((year, month, day): DateConversion) -> Result<Self> {
+        Date::new(year, month, day).or_else(|_| err("invalid Date"))
+    }
+}
+
+type TimeEncode = (i8, i8, i8, i32);
+type TimeDecode = (Hour, Minute, Second, Nanosecond);
+impl_convert!(Time, TimeEncode, TimeDecode);
+
+impl ConvertFrom<&Time> for TimeEncode {
+    #[inline(always)]
+    fn convert_from(value: &Time) -> Self {
+        (
+            value.hour(),
+            value.minute(),
+            value.second(),
+            value.subsec_nanosecond(),
+        )
+    }
+}
+
+impl ConvertFrom<TimeDecode> for Time {
+    #[inline(always)]
+    fn convert_from(value: TimeDecode) -> Self {
+        Time::new(
+            value.0.into_inner(),
+            value.1.into_inner(),
+            value.2.into_inner(),
+            value.3.into_inner(),
+        )
+        .unwrap()
+    }
+}
+
+impl_convert!(DateTime, (Date, Time));
+
+impl ConvertFrom<&DateTime> for (Date, Time) {
+    #[inline(always)]
+    fn convert_from(value: &DateTime) -> Self {
+        (value.date(), value.time())
+    }
+}
+
+impl ConvertFrom<(Date, Time)> for DateTime {
+    #[inline(always)]
+    fn convert_from((date, time): (Date, Time)) -> Self {
+        DateTime::from_parts(date, time)
+    }
+}
+
+impl_try_convert!(SignedDuration, SecondsNanos);
+
+impl ConvertFrom<&SignedDuration> for SecondsNanos {
+    #[inline(always)]
+    fn convert_from(value: &SignedDuration) -> Self {
+        (value.as_secs(), value.subsec_nanos())
+    }
+}
+
+impl TryConvertFrom<SecondsNanos> for SignedDuration {
+    #[inline(always)]
+    fn try_convert_from((secs, nanos): SecondsNanos) -> Result<Self> {
+        // SignedDuration::new would carry nanoseconds into seconds (which could overflow).
+        if !same_sign(secs, nanos) || nanos.unsigned_abs() >= 1_000_000_000 {
+            return err("invalid SignedDuration");
+        }
+        Ok(SignedDuration::new(secs, nanos))
+    }
+}
+
+/// Values with different signs have more than one representation, so they're invalid.
+#[inline(always)]
+fn same_sign(secs: i64, nanos: i32) -> bool {
+    secs == 0 || nanos == 0 || secs.signum() == nanos.signum() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use jiff::civil::{Date, DateTime, Time};
+    use jiff::{SignedDuration, Timestamp};
+
+    #[test]
+    fn timestamp() {
+        let v = vec![
+            Timestamp::MIN,
+            Timestamp::MAX,
+            Timestamp::UNIX_EPOCH,
+            Timestamp::new(-1, -5).unwrap(),
+        ];
+        assert_eq!(decode::<Vec<Timestamp>>(&encode(&v)).unwrap(), v);
+        // Encoded as integers.
+        assert_eq!(
+            encode(&Timestamp::new(1, 2).unwrap()),
+            encode(&(1i64, 2i32))
+        );
+        assert!(decode::<Timestamp>(&encode(&(i64::MAX, 0i32))).is_err());
+        assert!(decode::<Timestamp>(&encode(&(1i64, -1i32))).is_err());
+        assert!(decode::<Timestamp>(&encode(&(0i64, 1_000_000_000i32))).is_err());
+    }
+
+    #[test]
+    fn civil() {
+        let v = vec![
+            DateTime::MIN,
+            DateTime::MAX,
+            DateTime::constant(2024, 2, 29, 1, 2, 3, 4),
+        ];
+        assert_eq!(decode::<Vec<DateTime>>(&encode(&v)).unwrap(), v);
+
+        assert!(decode::<Date>(&encode(&(2023i16, 2i8, 29i8))).is_err());
+        assert!(decode::<Date>(&encode(&(2024i16, 13i8, 1i8))).is_err());
+        assert!(decode::<Date>(&encode(&(10_000i16, 1i8, 1i8))).is_err());
+
+        assert!(decode::<Time>(&encode(&(23i8, 59i8, 59i8, 999_999_999i32))).is_ok());
+        assert!(decode::<Time>(&encode(&(24i8, 0i8, 0i8, 0i32))).is_err());
+        assert!(decode::<Time>(&encode(&(0i8, 60i8, 0i8, 0i32))).is_err());
+        assert!(decode::<Time>(&encode(&(0i8, 0i8, 60i8, 0i32))).is_err());
+        assert!(decode::<Time>(&encode(&(0i8, 0i8, 0i8, -1i32))).is_err());
+    }
+
+    #[test]
+    fn signed_duration() {
+        let v = vec![
+            SignedDuration::MIN,
+            SignedDuration::MAX,
+            SignedDuration::ZERO,
+            SignedDuration::new(-1, -5),
+        ];
+        assert_eq!(decode::<Vec<SignedDuration>>(&encode(&v)).unwrap(), v);
+        assert!(decode::<SignedDuration>(&encode(&(1i64, -1i32))).is_err());
+        assert!(decode::<SignedDuration>(&encode(&(i64::MAX, 1_000_000_000i32))).is_err());
+    }
+
+    fn bench_data() -> Vec<Timestamp> {
+        crate::random_data(1000)
+            .into_iter()
+            .map(|(s, n): (u32, u32)| Timestamp::new(s as i64, (n % 1_000_000_000) as i32).unwrap())
+            .collect()
+    }
+    crate::bench_encode_decode!(timestamp_vec: Vec<Timestamp>);
+}
@@ -0,0 +1,90 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::int::{IntDecoder, IntEncoder};
+use crate::output::Output;
+use core::num::NonZeroUsize;
+
+/// Encodes the `N` little endian `u64` limbs of a fixed width integer as `N` columns, so the high
+/// limbs of small values pack well.
+pub struct LimbsEncoder<const N: usize>([IntEncoder<u64>; N]);
+
+impl<const N: usize> Default for LimbsEncoder<N> {
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| Default::default()))
+    }
+}
+
+impl<const N: usize> LimbsEncoder<N> {
+    #[inline(always)]
+    pub fn encode_limbs(&mut self, limbs: &[u64; N]) {
+        for (encoder, limb) in self.0.iter_mut().zip(limbs) {
+            encoder.encode(limb);
+        }
+    }
+}
+
+impl<const N: usize> Buffer for LimbsEncoder<N> {
+    fn collect_into(&mut self, out: &mut Output) {
+        for encoder in &mut self.0 {
+            encoder.collect_into(out);
+        }
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        for encoder in &mut self.0 {
+            encoder.reserve(additional);
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.iter().map(|e| e.heap_size()).sum()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.iter_mut().map(|encoder| encoder.encoded_len()).sum()
+    }
+
+    fn clear(&mut self) {
+        for encoder in &mut self.0 {
+            encoder.clear();
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        for encoder in &mut self.0 {
+            encoder.shrink_to_fit();
+        }
+    }
+}
+
+pub struct LimbsDecoder<'a, const N: usize>([IntDecoder<'a, u64>; N]);
+
+impl<const N: usize> Default for LimbsDecoder<'_, N> {
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| Default::default()))
+    }
+}
+
+impl<'a, const N: usize> LimbsDecoder<'a, N> {
+    /// Returns the decoder of the most significant limb (if there are any limbs).
+    pub fn last(&self) -> Option<&IntDecoder<'a, u64>> {
+        self.0.last()
+    }
+
+    #[inline(always)]
+    pub fn decode_limbs(&mut self) -> [u64; N] {
+        core::array::from_fn(|i| self.0[i].decode())
+    }
+}
+
+impl<'a, const N: usize> View<'a> for LimbsDecoder<'a, N> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        for decoder in &mut self.0 {
+            decoder.populate(input, length)?;
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.iter().map(|d| d.heap_size()).sum()
+    }
+}
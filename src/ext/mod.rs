@@ -1,12 +1,54 @@
+#[cfg(feature = "arcstr")]
+mod arcstr;
 #[cfg(feature = "arrayvec")]
 mod arrayvec;
+#[cfg(any(feature = "bitvec", feature = "fixedbitset"))]
+mod bits;
+#[cfg(feature = "bitvec")]
+mod bitvec;
+#[cfg(feature = "bytes")]
+pub(crate) mod bytes;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "compact_str")]
+mod compact_str;
+#[cfg(feature = "ecow")]
+mod ecow;
+#[cfg(feature = "fixedbitset")]
+mod fixedbitset;
 #[cfg(feature = "glam")]
 #[rustfmt::skip] // Makes impl_struct! calls way longer.
 mod glam;
+#[cfg(feature = "hashbrown")]
+mod hashbrown;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(any(feature = "primitive-types", feature = "ruint"))]
+mod limbs;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
+#[cfg(feature = "ruint")]
+mod ruint;
 #[cfg(feature = "rust_decimal")]
 mod rust_decimal;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "smol_str")]
+mod smol_str;
 #[cfg(feature = "time")]
 mod time;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
 #[cfg(feature = "uuid")]
 mod uuid;
 
@@ -29,7 +71,7 @@ macro_rules! impl_struct {
                 }
             }
             impl crate::coder::Buffer for StructEncoder {
-                fn collect_into(&mut self, out: &mut Vec<u8>) {
+                fn collect_into(&mut self, out: &mut crate::output::Output) {
                     $(
                         self.$f.collect_into(out);
                     )+
@@ -40,9 +82,31 @@ macro_rules! impl_struct {
                         self.$f.reserve(additional);
                     )+
                 }
+
+                fn heap_size(&self) -> usize {
+                    0 $(+ self.$f.heap_size())+
+                }
+
+                fn encoded_len(&mut self) -> usize {
+                    0 $(+ self.$f.encoded_len())+
+                }
+
+                fn clear(&mut self) {
+                    $(
+                        self.$f.clear();
+                    )+
+                }
+
+                fn shrink_to_fit(&mut self) {
+                    $(
+                        self.$f.shrink_to_fit();
+                    )+
+                }
             }
             impl crate::Encode for $t {
                 type Encoder = StructEncoder;
+                const MAX_ENCODED_LEN: Option<usize> =
+                    crate::derive::max_len::sum(&[$(<$ft as crate::Encode>::MAX_ENCODED_LEN),+]);
             }
 
             #[derive(Default)]
@@ -58,6 +122,10 @@ macro_rules! impl_struct {
                     )+
                     Ok(())
                 }
+
+                fn heap_size(&self) -> usize {
+                    0 $(+ self.$f.heap_size())+
+                }
             }
             impl<'a> crate::coder::Decoder<'a, $t> for StructDecoder<'a> {
                 // TODO use decode_in_place instead.
@@ -74,3 +142,41 @@ macro_rules! impl_struct {
 }
 #[allow(unused)]
 pub(crate) use impl_struct;
+
+/// Implements Encode/Decode for a string type with the same format as `String`. Decoding
+/// constructs it directly from a `&str` so short strings can be stored inline.
+#[allow(unused)]
+macro_rules! impl_str {
+    ($t:ty, $new:expr) => {
+        impl crate::coder::Encoder<$t> for crate::str::StrEncoder {
+            #[inline(always)]
+            fn encode(&mut self, t: &$t) {
+                self.encode(t.as_str());
+            }
+
+            #[inline(always)]
+            fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a $t> + Clone)
+            where
+                $t: 'a,
+            {
+                self.encode_vectored(i.map(|t| t.as_str()));
+            }
+        }
+        impl crate::Encode for $t {
+            type Encoder = crate::str::StrEncoder;
+        }
+
+        impl<'a> crate::coder::Decoder<'a, $t> for crate::str::StrDecoder<'a> {
+            #[inline(always)]
+            fn decode(&mut self) -> $t {
+                let v: &str = self.decode();
+                $new(v)
+            }
+        }
+        impl<'a> crate::Decode<'a> for $t {
+            type Decoder = crate::str::StrDecoder<'a>;
+        }
+    };
+}
+#[allow(unused)]
+pub(crate) use impl_str;
@@ -0,0 +1,297 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::convert::{
+    ConvertFrom, ConvertFromDecoder, ConvertIntoEncoder, TryConvertFrom, TryConvertFromDecoder,
+};
+use crate::derive::smart_ptr::DerefEncoder;
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use crate::output::Output;
+use core::num::NonZeroUsize;
+use nalgebra::{
+    Complex, Isometry, Point, Quaternion, RealField, SMatrix, SVector, Scalar, Translation, Unit,
+};
+
+/// Encodes each component of a matrix in its own column like glam's vectors and matrices.
+pub struct MatrixEncoder<T: Encode, const R: usize, const C: usize>([[T::Encoder; R]; C]);
+
+// Can't derive since it would bound T: Default.
+impl<T: Encode, const R: usize, const C: usize> Default for MatrixEncoder<T, R, C> {
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| {
+            core::array::from_fn(|_| Default::default())
+        }))
+    }
+}
+
+impl<T: Encode + Scalar, const R: usize, const C: usize> Encoder<SMatrix<T, R, C>>
+    for MatrixEncoder<T, R, C>
+{
+    #[inline(always)]
+    fn encode(&mut self, t: &SMatrix<T, R, C>) {
+        // Both are column major.
+        for (encoder, v) in self.0.iter_mut().flatten().zip(t.as_slice()) {
+            encoder.encode(v);
+        }
+    }
+}
+
+impl<T: Encode, const R: usize, const C: usize> Buffer for MatrixEncoder<T, R, C> {
+    fn collect_into(&mut self, out: &mut Output) {
+        for encoder in self.0.iter_mut().flatten() {
+            encoder.collect_into(out);
+        }
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        for encoder in self.0.iter_mut().flatten() {
+            encoder.reserve(additional);
+        }
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.as_flattened().iter().map(|e| e.heap_size()).sum()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0
+            .iter_mut()
+            .flatten()
+            .map(|encoder| encoder.encoded_len())
+            .sum()
+    }
+
+    fn clear(&mut self) {
+        for encoder in self.0.iter_mut().flatten() {
+            encoder.clear();
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        for encoder in self.0.iter_mut().flatten() {
+            encoder.shrink_to_fit();
+        }
+    }
+}
+
+pub struct MatrixDecoder<'a, T: Decode<'a>, const R: usize, const C: usize>([[T::Decoder; R]; C]);
+
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>, const R: usize, const C: usize> Default for MatrixDecoder<'a, T, R, C> {
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| {
+            core::array::from_fn(|_| Default::default())
+        }))
+    }
+}
+
+impl<'a, T: Decode<'a>, const R: usize, const C: usize> View<'a> for MatrixDecoder<'a, T, R, C> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        for decoder in self.0.iter_mut().flatten() {
+            decoder.populate(input, length)?;
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.as_flattened().iter().map(|d| d.heap_size()).sum()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        for d in self.0.as_flattened_mut() {
+            d.set_recursion_depth(depth);
+        }
+    }
+}
+
+impl<'a, T: Decode<'a> + Scalar, const R: usize, const C: usize> Decoder<'a, SMatrix<T, R, C>>
+    for MatrixDecoder<'a, T, R, C>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> SMatrix<T, R, C> {
+        SMatrix::from_iterator(self.0.iter_mut().flatten().map(|d| d.decode()))
+    }
+}
+
+// Also includes vectors such as `Vector3<T>` (`SMatrix<T, 3, 1>`).
+impl<T: Encode + Scalar, const R: usize, const C: usize> Encode for SMatrix<T, R, C> {
+    type Encoder = MatrixEncoder<T, R, C>;
+}
+impl<'a, T: Decode<'a> + Scalar, const R: usize, const C: usize> Decode<'a> for SMatrix<T, R, C> {
+    type Decoder = MatrixDecoder<'a, T, R, C>;
+}
+
+macro_rules! impl_wrapped_vector {
+    ($t:ident, $field:ident, |$v:ident| $new:expr) => {
+        impl<T: Scalar, const D: usize> ConvertFrom<&$t<T, D>> for SVector<T, D> {
+            #[inline(always)]
+            fn convert_from(value: &$t<T, D>) -> Self {
+                value.$field.clone()
+            }
+        }
+        impl<T: Scalar, const D: usize> ConvertFrom<SVector<T, D>> for $t<T, D> {
+            #[inline(always)]
+            fn convert_from($v: SVector<T, D>) -> Self {
+                $new
+            }
+        }
+        impl<T: Encode + Scalar, const D: usize> Encode for $t<T, D> {
+            type Encoder = ConvertIntoEncoder<SVector<T, D>>;
+        }
+        impl<'a, T: Decode<'a> + Scalar, const D: usize> Decode<'a> for $t<T, D> {
+            type Decoder = ConvertFromDecoder<'a, SVector<T, D>>;
+        }
+    };
+}
+impl_wrapped_vector!(Point, coords, |v| Point::from(v));
+impl_wrapped_vector!(Translation, vector, |v| Translation::from(v));
+
+// Encoded as [i, j, k, w] like glam's Quat.
+impl<T: Scalar> ConvertFrom<&Quaternion<T>> for SVector<T, 4> {
+    #[inline(always)]
+    fn convert_from(value: &Quaternion<T>) -> Self {
+        value.coords.clone()
+    }
+}
+impl<T: Scalar> ConvertFrom<SVector<T, 4>> for Quaternion<T> {
+    #[inline(always)]
+    fn convert_from(value: SVector<T, 4>) -> Self {
+        Quaternion::from(value)
+    }
+}
+impl<T: Encode + Scalar> Encode for Quaternion<T> {
+    type Encoder = ConvertIntoEncoder<SVector<T, 4>>;
+}
+impl<'a, T: Decode<'a> + Scalar> Decode<'a> for Quaternion<T> {
+    type Decoder = ConvertFromDecoder<'a, SVector<T, 4>>;
+}
+
+impl<T: Clone> ConvertFrom<&Complex<T>> for (T, T) {
+    #[inline(always)]
+    fn convert_from(value: &Complex<T>) -> Self {
+        (value.re.clone(), value.im.clone())
+    }
+}
+impl<T> ConvertFrom<(T, T)> for Complex<T> {
+    #[inline(always)]
+    fn convert_from((re, im): (T, T)) -> Self {
+        Complex::new(re, im)
+    }
+}
+impl<T: Encode + Clone> Encode for Complex<T> {
+    type Encoder = ConvertIntoEncoder<(T, T)>;
+}
+impl<'a, T: Decode<'a>> Decode<'a> for Complex<T> {
+    type Decoder = ConvertFromDecoder<'a, (T, T)>;
+}
+
+/// Same threshold as glam's `is_normalized`.
+#[inline(always)]
+fn is_normalized<T: RealField>(norm_squared: T) -> bool {
+    (norm_squared - T::one()).abs() <= nalgebra::convert(2e-4)
+}
+
+// Unit types are encoded like their inner type and rejected if they aren't normalized.
+macro_rules! impl_unit {
+    ($t:ty, |$v:ident| $norm_squared:expr, $($d:ident)?) => {
+        impl<T: RealField + Copy $(, const $d: usize)?> TryConvertFrom<$t> for Unit<$t> {
+            #[inline(always)]
+            fn try_convert_from($v: $t) -> Result<Self> {
+                if !is_normalized($norm_squared) {
+                    return err("invalid Unit");
+                }
+                Ok(Unit::new_unchecked($v))
+            }
+        }
+        impl<T: Encode + RealField + Copy $(, const $d: usize)?> Encode for Unit<$t> {
+            type Encoder = DerefEncoder<$t>;
+        }
+        impl<'a, T: Decode<'a> + RealField + Copy $(, const $d: usize)?> Decode<'a> for Unit<$t> {
+            type Decoder = TryConvertFromDecoder<'a, $t, Unit<$t>>;
+        }
+    };
+}
+impl_unit!(Quaternion<T>, |v| v.norm_squared(),);
+impl_unit!(Complex<T>, |v| v.norm_sqr(),);
+impl_unit!(SVector<T, D>, |v| v.norm_squared(), D);
+
+// Includes `Isometry2<T>` and `Isometry3<T>`.
+impl<T: Scalar, R: Clone, const D: usize> ConvertFrom<&Isometry<T, R, D>>
+    for (R, Translation<T, D>)
+{
+    #[inline(always)]
+    fn convert_from(value: &Isometry<T, R, D>) -> Self {
+        (value.rotation.clone(), value.translation.clone())
+    }
+}
+impl<T, R, const D: usize> ConvertFrom<(R, Translation<T, D>)> for Isometry<T, R, D> {
+    #[inline(always)]
+    fn convert_from((rotation, translation): (R, Translation<T, D>)) -> Self {
+        Isometry {
+            rotation,
+            translation,
+        }
+    }
+}
+impl<T: Encode + Scalar, R: Encode + Clone, const D: usize> Encode for Isometry<T, R, D> {
+    type Encoder = ConvertIntoEncoder<(R, Translation<T, D>)>;
+}
+impl<'a, T: Decode<'a> + Scalar, R: Decode<'a>, const D: usize> Decode<'a> for Isometry<T, R, D> {
+    type Decoder = ConvertFromDecoder<'a, (R, Translation<T, D>)>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use nalgebra::{
+        Complex, Isometry2, Isometry3, Matrix2x3, Point3, Quaternion, Unit, UnitComplex,
+        UnitQuaternion, UnitVector3, Vector2, Vector3, Vector4,
+    };
+
+    #[test]
+    fn vectors_and_matrices() {
+        let v = (
+            Vector2::new(1u8, 2),
+            Vector3::new(1.0f32, 2.0, 3.0),
+            Vector4::new(1i64, -2, 3, -4),
+            Matrix2x3::new(1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0),
+            Point3::new(1.0f32, 2.0, 3.0),
+        );
+        assert_eq!(decode::<(_, _, _, _, _)>(&encode(&v)).unwrap(), v);
+
+        // Same layout as glam (a column per component).
+        let vectors = vec![Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+        assert_eq!(
+            encode(&vectors),
+            encode(&vec![(1.0f32, 2.0f32, 3.0f32), (4.0, 5.0, 6.0)])
+        );
+    }
+
+    #[test]
+    fn units() {
+        let q = UnitQuaternion::from_euler_angles(0.1f32, 0.2, 0.3);
+        let c = UnitComplex::new(1.0f64);
+        let v = UnitVector3::new_normalize(Vector3::new(1.0f32, 2.0, 3.0));
+        let i2 = Isometry2::new(Vector2::new(1.0f64, 2.0), 0.5);
+        let i3 = Isometry3::new(Vector3::new(1.0f32, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+        let units = (q, c, v, i2, i3);
+        assert_eq!(decode::<(_, _, _, _, _)>(&encode(&units)).unwrap(), units);
+
+        // Not normalized.
+        let q = Quaternion::new(1.0f32, 1.0, 0.0, 0.0);
+        assert!(decode::<UnitQuaternion<f32>>(&encode(&q)).is_err());
+        assert!(decode::<UnitComplex<f64>>(&encode(&Complex::new(0.0f64, 0.0))).is_err());
+        let nan = Vector3::new(f32::NAN, 0.0, 0.0);
+        assert!(decode::<Unit<Vector3<f32>>>(&encode(&nan)).is_err());
+        let isometry = (Complex::new(2.0f64, 0.0), Vector2::new(0.0f64, 0.0));
+        assert!(decode::<Isometry2<f64>>(&encode(&isometry)).is_err());
+    }
+
+    fn bench_data() -> Vec<Isometry3<f32>> {
+        crate::random_data(1000)
+            .into_iter()
+            .map(|(t, r): ([f32; 3], [f32; 3])| Isometry3::new(t.into(), r.into()))
+            .collect()
+    }
+    crate::bench_encode_decode!(isometry3_vec: Vec<Isometry3<f32>>);
+}
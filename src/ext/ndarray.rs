@@ -0,0 +1,406 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::vec::{decode_elements_into, encode_elements};
+use crate::derive::{Decode, Encode};
+use crate::error::{err, error};
+use crate::length::{LengthDecoder, LengthEncoder};
+use crate::output::Output;
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+use ndarray::{ArcArray, Array, ArrayBase, ArrayView, CowArray, Data, Dimension};
+
+/// Encodes an array's shape as lengths (prefixed by the number of dimensions if it's dynamic) and
+/// its elements in logical order. A 1 dimensional array is encoded like a `Vec<A>`.
+pub struct ArrayEncoder<A: Encode> {
+    ndims: LengthEncoder,
+    dims: LengthEncoder,
+    elements: A::Encoder,
+}
+
+// Can't derive since it would bound A: Default.
+impl<A: Encode> Default for ArrayEncoder<A> {
+    fn default() -> Self {
+        Self {
+            ndims: Default::default(),
+            dims: Default::default(),
+            elements: Default::default(),
+        }
+    }
+}
+
+impl<A: Encode, S: Data<Elem = A>, D: Dimension> Encoder<ArrayBase<S, D>> for ArrayEncoder<A> {
+    #[inline(always)]
+    fn encode(&mut self, t: &ArrayBase<S, D>) {
+        let shape = t.shape();
+        if D::NDIM.is_none() {
+            self.ndims.encode(&shape.len());
+        }
+        if let Some(n) = NonZeroUsize::new(shape.len()) {
+            self.dims.reserve(n);
+            for dim in shape {
+                self.dims.encode(dim);
+            }
+        }
+
+        if let Some(elements) = t.as_slice() {
+            encode_elements(&mut self.elements, elements);
+        } else if let Some(n) = NonZeroUsize::new(t.len()) {
+            self.elements.reserve(n);
+            t.iter().for_each(|v| self.elements.encode(v));
+        }
+    }
+}
+
+impl<A: Encode> Buffer for ArrayEncoder<A> {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.ndims.collect_into(out);
+        self.dims.collect_into(out);
+        self.elements.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.ndims.reserve(additional);
+        // Dims and elements are reserved in encode since they depend on the shape.
+    }
+
+    fn heap_size(&self) -> usize {
+        self.ndims.heap_size() + self.dims.heap_size() + self.elements.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.ndims.encoded_len() + self.dims.encoded_len() + self.elements.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.ndims.clear();
+        self.dims.clear();
+        self.elements.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.ndims.shrink_to_fit();
+        self.dims.shrink_to_fit();
+        self.elements.shrink_to_fit();
+    }
+}
+
+impl<A: Encode, S: Data<Elem = A>, D: Dimension> Encode for ArrayBase<S, D> {
+    type Encoder = ArrayEncoder<A>;
+}
+
+pub struct ShapeDecoder<'a, D> {
+    ndims: LengthDecoder<'a>,
+    dims: LengthDecoder<'a>,
+    elements: usize,
+    _spooky: core::marker::PhantomData<fn() -> D>,
+}
+
+// Can't derive since it would bound D: Default.
+impl<D> Default for ShapeDecoder<'_, D> {
+    fn default() -> Self {
+        Self {
+            ndims: Default::default(),
+            dims: Default::default(),
+            elements: 0,
+            _spooky: Default::default(),
+        }
+    }
+}
+
+impl<'a, D: Dimension> View<'a> for ShapeDecoder<'a, D> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        let dims = if let Some(ndim) = D::NDIM {
+            self.ndims.populate(input, 0)?;
+            length
+                .checked_mul(ndim)
+                .ok_or_else(|| error("length overflow"))?
+        } else {
+            self.ndims.populate(input, length)?;
+            self.ndims.length()
+        };
+        self.dims.populate(input, dims)?;
+
+        // Validate shapes like ndarray (the product of non-zero dims must be <= isize::MAX) and
+        // count the elements.
+        let mut ndims = self.ndims.borrowed_clone();
+        let mut dims = self.dims.borrowed_clone();
+        let mut elements = 0usize;
+        for _ in 0..length {
+            let ndim = D::NDIM.unwrap_or_else(|| ndims.decode());
+            let mut product = 1usize;
+            let mut zero = false;
+            for _ in 0..ndim {
+                let dim: usize = dims.decode();
+                zero |= dim == 0;
+                product = product
+                    .checked_mul(dim.max(1))
+                    .filter(|&p| p <= isize::MAX as usize)
+                    .ok_or_else(|| error("invalid ndarray shape"))?;
+            }
+            if !zero {
+                elements = elements
+                    .checked_add(product)
+                    .ok_or_else(|| error("length overflow"))?;
+            }
+        }
+        self.elements = elements;
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.ndims.heap_size() + self.dims.heap_size()
+    }
+}
+
+impl<D: Dimension> ShapeDecoder<'_, D> {
+    #[inline(always)]
+    fn decode(&mut self) -> D {
+        let ndim = D::NDIM.unwrap_or_else(|| self.ndims.decode());
+        let mut shape = D::zeros(ndim);
+        for i in 0..ndim {
+            shape[i] = self.dims.decode();
+        }
+        shape
+    }
+}
+
+pub struct ArrayDecoder<'a, A: Decode<'a>, D> {
+    shapes: ShapeDecoder<'a, D>,
+    elements: A::Decoder,
+}
+
+// Can't derive since it would bound A: Default.
+impl<'a, A: Decode<'a>, D> Default for ArrayDecoder<'a, A, D> {
+    fn default() -> Self {
+        Self {
+            shapes: Default::default(),
+            elements: Default::default(),
+        }
+    }
+}
+
+impl<'a, A: Decode<'a>, D: Dimension> View<'a> for ArrayDecoder<'a, A, D> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.shapes.populate(input, length)?;
+        self.elements.populate(input, self.shapes.elements)
+    }
+
+    fn heap_size(&self) -> usize {
+        self.shapes.heap_size() + self.elements.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.elements.set_recursion_depth(depth);
+    }
+}
+
+impl<'a, A: Decode<'a>, D: Dimension> Decoder<'a, Array<A, D>> for ArrayDecoder<'a, A, D> {
+    #[inline(always)]
+    fn decode(&mut self) -> Array<A, D> {
+        let shape = self.shapes.decode();
+        let size = shape.size();
+        let mut v = Vec::with_capacity(size);
+        decode_elements_into::<A>(&mut self.elements, &mut v.spare_capacity_mut()[..size]);
+        // Safety: `size` elements were just initialized.
+        unsafe { v.set_len(size) };
+        // Safety: `v` has `shape.size()` elements in standard layout and populate checked `shape`.
+        unsafe { Array::from_shape_vec_unchecked(shape, v) }
+    }
+}
+
+impl<'a, A: Decode<'a> + Clone, D: Dimension> Decoder<'a, ArcArray<A, D>>
+    for ArrayDecoder<'a, A, D>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> ArcArray<A, D> {
+        let array: Array<A, D> = self.decode();
+        array.into_shared()
+    }
+}
+
+impl<'a, A: Decode<'a>, D: Dimension> Decode<'a> for Array<A, D> {
+    type Decoder = ArrayDecoder<'a, A, D>;
+}
+impl<'a, A: Decode<'a> + Clone, D: Dimension> Decode<'a> for ArcArray<A, D> {
+    type Decoder = ArrayDecoder<'a, A, D>;
+}
+
+/// Decodes arrays by borrowing their elements from the input. Only possible for byte-like
+/// primitives (e.g. `u8`) which weren't packed, so [`CowArray`] should be used unless the bytes are
+/// known to be incompressible.
+pub struct ArrayViewDecoder<'a, A, D> {
+    shapes: ShapeDecoder<'a, D>,
+    elements: &'a [A],
+}
+
+// Can't derive since it would bound A: Default.
+impl<A, D> Default for ArrayViewDecoder<'_, A, D> {
+    fn default() -> Self {
+        Self {
+            shapes: Default::default(),
+            elements: &[],
+        }
+    }
+}
+
+impl<'a, A: Decode<'a>, D: Dimension> View<'a> for ArrayViewDecoder<'a, A, D> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.shapes.populate(input, length)?;
+        let mut elements = A::Decoder::default();
+        elements.populate(input, self.shapes.elements)?;
+        // Safety: `elements` was populated with `self.shapes.elements` elements.
+        let Some(elements) = (unsafe { elements.decode_borrowed(self.shapes.elements) }) else {
+            return err("can't borrow ArrayView elements");
+        };
+        self.elements = elements;
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.shapes.heap_size()
+    }
+}
+
+impl<'a, A: Decode<'a> + Sync, D: Dimension> Decoder<'a, ArrayView<'a, A, D>>
+    for ArrayViewDecoder<'a, A, D>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> ArrayView<'a, A, D> {
+        let shape = self.shapes.decode();
+        // Safety: populate counted `shape.size()` elements for this array.
+        let (elements, remaining) = unsafe { self.elements.split_at_unchecked(shape.size()) };
+        self.elements = remaining;
+        // Safety: `elements` has `shape.size()` elements in standard layout and populate checked
+        // `shape`.
+        unsafe { ArrayView::from_shape_ptr(shape, elements.as_ptr()) }
+    }
+}
+
+impl<'a, A: Decode<'a> + Sync, D: Dimension> Decode<'a> for ArrayView<'a, A, D> {
+    type Decoder = ArrayViewDecoder<'a, A, D>;
+}
+
+/// Borrows elements like [`ArrayViewDecoder`] if possible, otherwise copies them like
+/// [`ArrayDecoder`].
+pub struct CowArrayDecoder<'a, A: Decode<'a>, D> {
+    owned: ArrayDecoder<'a, A, D>,
+    borrowed: Option<&'a [A]>,
+}
+
+// Can't derive since it would bound A: Default.
+impl<'a, A: Decode<'a>, D> Default for CowArrayDecoder<'a, A, D> {
+    fn default() -> Self {
+        Self {
+            owned: Default::default(),
+            borrowed: None,
+        }
+    }
+}
+
+impl<'a, A: Decode<'a>, D: Dimension> View<'a> for CowArrayDecoder<'a, A, D> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.owned.populate(input, length)?;
+        let elements = self.owned.shapes.elements;
+        // Safety: `self.owned.elements` was populated with `elements` elements. If it returns
+        // `None` nothing was decoded, so they can still be decoded by `self.owned`.
+        self.borrowed = unsafe { self.owned.elements.decode_borrowed(elements) };
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.owned.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.owned.set_recursion_depth(depth);
+    }
+}
+
+impl<'a, A: Decode<'a> + Sync, D: Dimension> Decoder<'a, CowArray<'a, A, D>>
+    for CowArrayDecoder<'a, A, D>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> CowArray<'a, A, D> {
+        if let Some(borrowed) = &mut self.borrowed {
+            let shape = self.owned.shapes.decode();
+            // Safety: same as ArrayViewDecoder::decode.
+            let (elements, remaining) = unsafe { borrowed.split_at_unchecked(shape.size()) };
+            *borrowed = remaining;
+            unsafe { ArrayView::from_shape_ptr(shape, elements.as_ptr()) }.into()
+        } else {
+            let owned: Array<A, D> = self.owned.decode();
+            owned.into()
+        }
+    }
+}
+
+impl<'a, A: Decode<'a> + Sync, D: Dimension> Decode<'a> for CowArray<'a, A, D> {
+    type Decoder = CowArrayDecoder<'a, A, D>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use ndarray::{arr0, arr2, Array1, Array2, Array3, ArrayD, ArrayView2, CowArray, IxDyn};
+
+    #[test]
+    fn array() {
+        let v = (
+            arr0(1u8),
+            arr2(&[[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]),
+            Array3::<u16>::from_shape_fn((2, 0, 3), |_| unreachable!()),
+            ArrayD::from_shape_fn(IxDyn(&[2, 3, 4, 5]), |d| d[0] as i64 - d[3] as i64),
+        );
+        assert_eq!(decode::<(_, _, _, _)>(&encode(&v)).unwrap(), v);
+
+        // Encoded in logical order, so layout doesn't matter.
+        let a = arr2(&[[1u32, 2], [3, 4], [5, 6]]);
+        assert_eq!(encode(&a.t()), encode(&a.t().to_owned()));
+        assert_eq!(decode::<Array2<u32>>(&encode(&a.t())).unwrap(), a.t());
+
+        // 1 dimensional arrays are encoded like Vec.
+        let v = vec![1u32, 2, 3];
+        assert_eq!(encode(&Array1::from(v.clone())), encode(&v));
+    }
+
+    #[test]
+    fn invalid_shape() {
+        // ArrayD<()> is encoded like Vec<Vec<()>> with the inner lengths as dims.
+        let shape = |dims: &[usize]| {
+            let v: Vec<Vec<()>> = dims.iter().map(|&d| vec![(); d]).collect();
+            decode::<ArrayD<()>>(&encode(&v)).map(|a| a.shape().to_vec())
+        };
+        assert_eq!(shape(&[3, 0, 2]).unwrap(), [3, 0, 2]);
+        assert!(shape(&[1 << 21; 3]).is_err()); // > isize::MAX
+        assert!(shape(&[1 << 22, 1 << 22, 1 << 22, 0]).is_err()); // Overflows.
+    }
+
+    #[test]
+    fn array_view() {
+        // Random bytes can't be packed, so they're borrowed.
+        let a = Array2::from_shape_vec((10, 100), crate::random_data::<u8>(1000)).unwrap();
+        let encoded = encode(&a);
+        let view = decode::<ArrayView2<u8>>(&encoded).unwrap();
+        assert_eq!(view, a);
+        assert!(encoded.as_ptr_range().contains(&view.as_ptr()));
+        let cow = decode::<CowArray<u8, _>>(&encoded).unwrap();
+        assert!(cow.is_view());
+        assert_eq!(cow, a);
+
+        // Packed bytes can't be borrowed.
+        let a = Array2::<u8>::zeros((10, 100));
+        let encoded = encode(&a);
+        assert!(decode::<ArrayView2<u8>>(&encoded).is_err());
+        let cow = decode::<CowArray<u8, _>>(&encoded).unwrap();
+        assert!(cow.is_owned());
+        assert_eq!(cow, a);
+    }
+
+    fn bench_data() -> Vec<Array2<f32>> {
+        crate::random_data::<[f32; 12]>(100)
+            .into_iter()
+            .map(|v| Array2::from_shape_vec((3, 4), v.to_vec()).unwrap())
+            .collect()
+    }
+    crate::bench_encode_decode!(array2_vec: Vec<Array2<f32>>);
+}
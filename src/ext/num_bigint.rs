@@ -0,0 +1,242 @@
+use crate::bool::BoolDecoder;
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use crate::output::Output;
+use core::num::NonZeroUsize;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// Encodes a [`BigUint`]'s `u32` digits like a `Vec<u32>` (a length and packed digits).
+#[derive(Default)]
+pub struct BigUintEncoder(VecEncoder<u32>);
+
+impl Encoder<BigUint> for BigUintEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &BigUint) {
+        // Doesn't allocate unlike BigUint::to_u32_digits.
+        let digits = t.iter_u32_digits();
+        let n = digits.len();
+        self.0.lengths.encode(&n);
+        if let Some(n) = NonZeroUsize::new(n) {
+            self.0.elements.reserve(n);
+            digits.for_each(|d| self.0.elements.encode(&d));
+        }
+    }
+}
+
+impl Buffer for BigUintEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.0.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.0.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
+/// Decodes a [`BigUint`]'s digits like a `Vec<u32>`. Fails if the last digit is zero, so each value
+/// has only 1 valid encoding.
+#[derive(Default)]
+pub struct BigUintDecoder<'a>(VecDecoder<'a, u32>);
+
+impl<'a> View<'a> for BigUintDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.0.populate(input, length)?;
+
+        let mut lengths = self.0.lengths.borrowed_clone();
+        let mut digits = self.0.elements.borrowed_clone();
+        for _ in 0..length {
+            let n = lengths.decode();
+            let mut last = 1;
+            for _ in 0..n {
+                last = digits.decode();
+            }
+            if last == 0 {
+                return err("non-canonical BigUint");
+            }
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+impl<'a> Decoder<'a, BigUint> for BigUintDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> BigUint {
+        BigUint::new(self.0.decode())
+    }
+}
+
+impl Encode for BigUint {
+    type Encoder = BigUintEncoder;
+}
+impl<'a> Decode<'a> for BigUint {
+    type Decoder = BigUintDecoder<'a>;
+}
+
+/// Encodes a [`BigInt`] as whether it's negative and its magnitude.
+#[derive(Default)]
+pub struct BigIntEncoder {
+    negative: <bool as Encode>::Encoder,
+    magnitude: BigUintEncoder,
+}
+
+impl Encoder<BigInt> for BigIntEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &BigInt) {
+        self.negative.encode(&(t.sign() == Sign::Minus));
+        self.magnitude.encode(t.magnitude());
+    }
+}
+
+impl Buffer for BigIntEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        self.negative.collect_into(out);
+        self.magnitude.collect_into(out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.negative.reserve(additional);
+        self.magnitude.reserve(additional);
+    }
+
+    fn heap_size(&self) -> usize {
+        self.negative.heap_size() + self.magnitude.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.negative.encoded_len() + self.magnitude.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.negative.clear();
+        self.magnitude.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.negative.shrink_to_fit();
+        self.magnitude.shrink_to_fit();
+    }
+}
+
+/// Decodes a [`BigInt`] as whether it's negative and its magnitude. Fails on negative zero.
+#[derive(Default)]
+pub struct BigIntDecoder<'a> {
+    negative: BoolDecoder<'a>,
+    magnitude: BigUintDecoder<'a>,
+}
+
+impl<'a> View<'a> for BigIntDecoder<'a> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.negative.populate(input, length)?;
+        self.magnitude.populate(input, length)?;
+
+        let mut negative = self.negative.borrowed_clone();
+        let mut lengths = self.magnitude.0.lengths.borrowed_clone();
+        for _ in 0..length {
+            let (negative, n) = (negative.decode(), lengths.decode());
+            if negative && n == 0 {
+                return err("negative zero BigInt");
+            }
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.negative.heap_size() + self.magnitude.heap_size()
+    }
+}
+
+impl<'a> Decoder<'a, BigInt> for BigIntDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> BigInt {
+        let sign = if self.negative.decode() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        // Zero is always NoSign, but populate already rejected negative zero.
+        BigInt::from_biguint(sign, self.magnitude.decode())
+    }
+}
+
+impl Encode for BigInt {
+    type Encoder = BigIntEncoder;
+}
+impl<'a> Decode<'a> for BigInt {
+    type Decoder = BigIntDecoder<'a>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use num_bigint::{BigInt, BigUint};
+
+    #[test]
+    fn big_uint() {
+        let big = BigUint::from(u128::MAX) * BigUint::from(u128::MAX);
+        let v = vec![
+            BigUint::ZERO,
+            BigUint::from(1u8),
+            BigUint::from(u64::MAX),
+            big,
+        ];
+        let encoded = encode(&v);
+        assert_eq!(decode::<Vec<BigUint>>(&encoded).unwrap(), v);
+
+        // Encoded like Vec<u32>, so small values stay small.
+        assert_eq!(encode(&BigUint::from(5u8)), encode(&vec![5u32]));
+        assert_eq!(encode(&BigUint::ZERO), encode(&Vec::<u32>::new()));
+
+        // Trailing zero digits aren't canonical.
+        assert!(decode::<BigUint>(&encode(&vec![5u32, 0])).is_err());
+        assert!(decode::<BigUint>(&encode(&vec![0u32])).is_err());
+        assert!(decode::<Vec<BigUint>>(&encode(&vec![vec![1u32], vec![], vec![2, 0]])).is_err());
+    }
+
+    #[test]
+    fn big_int() {
+        let big = BigInt::from(i128::MIN) * BigInt::from(i128::MAX);
+        let v = vec![BigInt::ZERO, BigInt::from(-1), BigInt::from(i64::MIN), big];
+        assert_eq!(decode::<Vec<BigInt>>(&encode(&v)).unwrap(), v);
+
+        assert_eq!(encode(&BigInt::from(-5)), encode(&(true, vec![5u32])));
+        assert_eq!(encode(&BigInt::ZERO), encode(&(false, Vec::<u32>::new())));
+
+        // Negative zero and trailing zero digits aren't canonical.
+        assert!(decode::<BigInt>(&encode(&(true, Vec::<u32>::new()))).is_err());
+        assert!(decode::<BigInt>(&encode(&(true, vec![5u32, 0]))).is_err());
+        assert!(
+            decode::<Vec<BigInt>>(&encode(&vec![(false, vec![1u32]), (true, vec![])])).is_err()
+        );
+    }
+
+    fn bench_data() -> Vec<BigInt> {
+        crate::random_data(1000)
+            .into_iter()
+            .map(|v: i64| BigInt::from(v) * BigInt::from(v))
+            .collect()
+    }
+    crate::bench_encode_decode!(big_int_vec: Vec<BigInt>);
+}
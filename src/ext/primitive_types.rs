@@ -0,0 +1,62 @@
+use super::limbs::{LimbsDecoder, LimbsEncoder};
+use crate::coder::{Decoder, Encoder};
+use crate::derive::{Decode, Encode};
+use primitive_types::{U128, U256, U512};
+
+macro_rules! impl_uint {
+    ($t:ident, $n:literal) => {
+        impl Encoder<$t> for LimbsEncoder<$n> {
+            #[inline(always)]
+            fn encode(&mut self, t: &$t) {
+                self.encode_limbs(&t.0);
+            }
+        }
+        impl Encode for $t {
+            type Encoder = LimbsEncoder<$n>;
+        }
+
+        impl<'a> Decoder<'a, $t> for LimbsDecoder<'a, $n> {
+            #[inline(always)]
+            fn decode(&mut self) -> $t {
+                $t(self.decode_limbs())
+            }
+        }
+        impl<'a> Decode<'a> for $t {
+            type Decoder = LimbsDecoder<'a, $n>;
+        }
+    };
+}
+impl_uint!(U128, 2);
+impl_uint!(U256, 4);
+impl_uint!(U512, 8);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use primitive_types::{U128, U256, U512};
+
+    #[test]
+    fn uint() {
+        let v = (U128::MAX, U256::from(12345u64), U512::MAX - 1);
+        assert_eq!(decode::<(_, _, _)>(&encode(&v)).unwrap(), v);
+
+        // A column per limb.
+        let v = vec![U256([1, 2, 3, 4]), U256([5, 6, 7, 8])];
+        assert_eq!(
+            encode(&v),
+            encode(&vec![(1u64, 2u64, 3u64, 4u64), (5, 6, 7, 8)])
+        );
+
+        // Small values stay small (instead of 32 bytes each).
+        let small: Vec<U256> = (0..100u64).map(U256::from).collect();
+        let encoded = encode(&small);
+        assert!(encoded.len() < 200, "{}", encoded.len());
+        assert_eq!(decode::<Vec<U256>>(&encoded).unwrap(), small);
+    }
+
+    fn bench_data() -> Vec<U256> {
+        crate::random_data(1000).into_iter().map(U256).collect()
+    }
+    crate::bench_encode_decode!(u256_vec: Vec<U256>);
+}
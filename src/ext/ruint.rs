@@ -0,0 +1,100 @@
+use super::limbs::{LimbsDecoder, LimbsEncoder};
+use crate::coder::{Decoder, Encoder, Result, View};
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use ruint::Uint;
+
+impl<const BITS: usize, const LIMBS: usize> Encoder<Uint<BITS, LIMBS>> for LimbsEncoder<LIMBS> {
+    #[inline(always)]
+    fn encode(&mut self, t: &Uint<BITS, LIMBS>) {
+        self.encode_limbs(t.as_limbs());
+    }
+}
+impl<const BITS: usize, const LIMBS: usize> Encode for Uint<BITS, LIMBS> {
+    type Encoder = LimbsEncoder<LIMBS>;
+}
+
+/// Rejects values that don't fit in `BITS` (e.g. `Uint<65, 2>` with bits set above bit 65).
+pub struct UintDecoder<'a, const BITS: usize, const LIMBS: usize>(LimbsDecoder<'a, LIMBS>);
+
+impl<const BITS: usize, const LIMBS: usize> Default for UintDecoder<'_, BITS, LIMBS> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize> View<'a> for UintDecoder<'a, BITS, LIMBS> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.0.populate(input, length)?;
+        if let Some(last) = self.0.last() {
+            let mask = Uint::<BITS, LIMBS>::MASK;
+            let mut decoder = last.borrowed_clone();
+            // Optimizes much better than Iterator::any.
+            if (0..length)
+                .filter(|_| Decoder::<u64>::decode(&mut decoder) & !mask != 0)
+                .count()
+                != 0
+            {
+                return err("invalid Uint");
+            }
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize> Decoder<'a, Uint<BITS, LIMBS>>
+    for UintDecoder<'a, BITS, LIMBS>
+{
+    #[inline(always)]
+    fn decode(&mut self) -> Uint<BITS, LIMBS> {
+        // Can't panic since populate checked the most significant limb.
+        Uint::from_limbs(self.0.decode_limbs())
+    }
+}
+
+impl<'a, const BITS: usize, const LIMBS: usize> Decode<'a> for Uint<BITS, LIMBS> {
+    type Decoder = UintDecoder<'a, BITS, LIMBS>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use ruint::aliases::{U0, U256, U64};
+    use ruint::Uint;
+
+    type U65 = Uint<65, 2>;
+
+    #[test]
+    fn uint() {
+        let v = (
+            U256::MAX,
+            U256::from(12345u64),
+            U65::MAX,
+            U64::from(1u64),
+            U0::ZERO,
+        );
+        assert_eq!(decode::<(_, _, _, _, _)>(&encode(&v)).unwrap(), v);
+
+        // Same format as primitive_types::U256.
+        let v = vec![U256::from_limbs([1, 2, 3, 4])];
+        assert_eq!(encode(&v), encode(&vec![(1u64, 2u64, 3u64, 4u64)]));
+
+        // Bits above BITS aren't valid.
+        assert!(decode::<U65>(&encode(&(0u64, 1u64))).is_ok());
+        assert!(decode::<U65>(&encode(&(0u64, 2u64))).is_err());
+        assert!(decode::<Uint<1, 1>>(&encode(&2u64)).is_err());
+    }
+
+    fn bench_data() -> Vec<U256> {
+        crate::random_data(1000)
+            .into_iter()
+            .map(U256::from_limbs)
+            .collect()
+    }
+    crate::bench_encode_decode!(ruint_vec: Vec<U256>);
+}
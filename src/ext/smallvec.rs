@@ -0,0 +1,84 @@
+use crate::coder::{Decoder, Encoder};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use core::mem::MaybeUninit;
+use smallvec::{Array, SmallVec};
+
+impl<A: Array> Encoder<SmallVec<A>> for VecEncoder<A::Item>
+where
+    A::Item: Encode,
+{
+    #[inline(always)]
+    fn encode(&mut self, t: &SmallVec<A>) {
+        self.encode(t.as_slice());
+    }
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a SmallVec<A>> + Clone)
+    where
+        SmallVec<A>: 'a,
+    {
+        self.encode_vectored(i.map(SmallVec::as_slice));
+    }
+}
+impl<A: Array> Encode for SmallVec<A>
+where
+    A::Item: Encode,
+{
+    type Encoder = VecEncoder<A::Item>;
+}
+
+impl<'a, A: Array> Decoder<'a, SmallVec<A>> for VecDecoder<'a, A::Item>
+where
+    A::Item: Decode<'a>,
+{
+    #[inline(always)]
+    fn decode_in_place(&mut self, out: &mut MaybeUninit<SmallVec<A>>) {
+        let n = self.lengths.decode();
+        // Only allocates if `n` doesn't fit inline.
+        let v = out.write(SmallVec::with_capacity(n));
+        // Safety: `v` has capacity for `n` elements and they're initialized before set_len.
+        unsafe {
+            let spare = core::slice::from_raw_parts_mut(v.as_mut_ptr() as *mut MaybeUninit<_>, n);
+            self.decode_elements_into(spare);
+            v.set_len(n);
+        }
+    }
+}
+impl<'a, A: Array> Decode<'a> for SmallVec<A>
+where
+    A::Item: Decode<'a>,
+{
+    type Decoder = VecDecoder<'a, A::Item>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use smallvec::{smallvec, SmallVec};
+
+    #[test]
+    fn small_vec() {
+        let inline: SmallVec<[u8; 4]> = smallvec![1, 2];
+        let heap: SmallVec<[u8; 4]> = (0..10).collect();
+        let v = vec![inline, heap];
+        let encoded = encode(&v);
+        assert_eq!(encoded, encode(&vec![vec![1u8, 2], (0..10).collect()]));
+        let decoded: Vec<SmallVec<[u8; 4]>> = decode(&encoded).unwrap();
+        assert_eq!(decoded, v);
+        assert!(!decoded[0].spilled());
+        assert!(decoded[1].spilled());
+
+        let v: SmallVec<[String; 1]> = smallvec![String::from("a"), String::from("bc")];
+        assert_eq!(decode::<SmallVec<[String; 1]>>(&encode(&v)).unwrap(), v);
+    }
+
+    fn bench_data() -> Vec<SmallVec<[u8; 8]>> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| (0..n % 16).collect())
+            .collect()
+    }
+    crate::bench_encode_decode!(small_vec_vec: Vec<SmallVec<[u8; 8]>>);
+}
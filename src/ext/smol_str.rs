@@ -0,0 +1,45 @@
+use smol_str::SmolStr;
+
+// Strings up to 23 bytes are decoded inline without allocating.
+super::impl_str!(SmolStr, SmolStr::new);
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::vec::Vec;
+    use smol_str::SmolStr;
+
+    #[test]
+    fn smol_str() {
+        let v = vec![
+            SmolStr::new("abc"),
+            SmolStr::new("☺"),
+            SmolStr::new(""),
+            SmolStr::new("a string that is too long to be inline"),
+        ];
+        let encoded = encode(&v);
+        assert_eq!(
+            encoded,
+            encode(&vec![
+                "abc",
+                "☺",
+                "",
+                "a string that is too long to be inline"
+            ])
+        );
+        let decoded = decode::<Vec<SmolStr>>(&encoded).unwrap();
+        assert_eq!(decoded, v);
+        assert!(!decoded[0].is_heap_allocated());
+
+        // Shares the UTF-8 validation of String.
+        assert!(decode::<SmolStr>(&encode(&vec![0xFFu8])).is_err());
+    }
+
+    fn bench_data() -> Vec<SmolStr> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| SmolStr::new(" ".repeat(n as usize % 32)))
+            .collect()
+    }
+    crate::bench_encode_decode!(smol_str_vec: Vec<SmolStr>);
+}
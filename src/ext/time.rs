@@ -1,11 +1,20 @@
-use crate::convert::{impl_convert, ConvertFrom};
+use crate::coder::Result;
+use crate::convert::{impl_convert, impl_try_convert, ConvertFrom, TryConvertFrom};
+use crate::error::err;
 use crate::int::ranged_int;
-use time::Time;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 ranged_int!(Hour, u8, 0, 23);
 ranged_int!(Minute, u8, 0, 59);
 ranged_int!(Second, u8, 0, 59);
 ranged_int!(Nanosecond, u32, 0, 999_999_999);
+ranged_int!(Year, i32, Date::MIN.year(), Date::MAX.year());
+ranged_int!(Ordinal, u16, 1, 366);
+ranged_int!(OffsetHour, i8, -25, 25);
+ranged_int!(OffsetMinute, i8, -59, 59);
+ranged_int!(OffsetSecond, i8, -59, 59);
+ranged_int!(MonthNumber, u8, 1, 12);
+ranged_int!(WeekdayNumber, u8, 0, 6);
 
 type TimeEncode = (u8, u8, u8, u32);
 type TimeDecode = (Hour, Minute, Second, Nanosecond);
@@ -31,6 +40,119 @@ impl ConvertFrom<TimeDecode> for Time {
     }
 }
 
+type DateEncode = (i32, u16);
+type DateDecode = (Year, Ordinal);
+
+impl ConvertFrom<&Date> for DateEncode {
+    #[inline(always)]
+    fn convert_from(value: &Date) -> Self {
+        value.to_ordinal_date()
+    }
+}
+
+impl TryConvertFrom<DateDecode> for Date {
+    #[inline(always)]
+    fn try_convert_from(value: DateDecode) -> Result<Self> {
+        // Ordinal 366 is only valid in leap years.
+        Date::from_ordinal_date(value.0.into_inner(), value.1.into_inner())
+            .or_else(|_| err("invalid Date"))
+    }
+}
+
+impl_try_convert!(Date, DateEncode, DateDecode);
+
+impl_convert!(PrimitiveDateTime, (Date, Time));
+
+impl ConvertFrom<&PrimitiveDateTime> for (Date, Time) {
+    #[inline(always)]
+    fn convert_from(value: &PrimitiveDateTime) -> Self {
+        (value.date(), value.time())
+    }
+}
+
+impl ConvertFrom<(Date, Time)> for PrimitiveDateTime {
+    #[inline(always)]
+    fn convert_from((date, time): (Date, Time)) -> Self {
+        PrimitiveDateTime::new(date, time)
+    }
+}
+
+type UtcOffsetEncode = (i8, i8, i8);
+type UtcOffsetDecode = (OffsetHour, OffsetMinute, OffsetSecond);
+impl_convert!(UtcOffset, UtcOffsetEncode, UtcOffsetDecode);
+
+impl ConvertFrom<&UtcOffset> for UtcOffsetEncode {
+    #[inline(always)]
+    fn convert_from(value: &UtcOffset) -> Self {
+        value.as_hms()
+    }
+}
+
+impl ConvertFrom<UtcOffsetDecode> for UtcOffset {
+    #[inline(always)]
+    fn convert_from(value: UtcOffsetDecode) -> Self {
+        // Mismatched signs are flipped instead of returning an error.
+        UtcOffset::from_hms(
+            value.0.into_inner(),
+            value.1.into_inner(),
+            value.2.into_inner(),
+        )
+        .unwrap()
+    }
+}
+
+// Like serde, encodes the local date and time instead of the UTC date and time.
+impl_convert!(OffsetDateTime, (PrimitiveDateTime, UtcOffset));
+
+impl ConvertFrom<&OffsetDateTime> for (PrimitiveDateTime, UtcOffset) {
+    #[inline(always)]
+    fn convert_from(value: &OffsetDateTime) -> Self {
+        (
+            PrimitiveDateTime::new(value.date(), value.time()),
+            value.offset(),
+        )
+    }
+}
+
+impl ConvertFrom<(PrimitiveDateTime, UtcOffset)> for OffsetDateTime {
+    #[inline(always)]
+    fn convert_from((date_time, offset): (PrimitiveDateTime, UtcOffset)) -> Self {
+        date_time.assume_offset(offset)
+    }
+}
+
+impl_convert!(Month, u8, MonthNumber);
+
+impl ConvertFrom<&Month> for u8 {
+    #[inline(always)]
+    fn convert_from(value: &Month) -> Self {
+        *value as u8
+    }
+}
+
+impl ConvertFrom<MonthNumber> for Month {
+    #[inline(always)]
+    fn convert_from(value: MonthNumber) -> Self {
+        Month::try_from(value.into_inner()).unwrap()
+    }
+}
+
+impl_convert!(Weekday, u8, WeekdayNumber);
+
+impl ConvertFrom<&Weekday> for u8 {
+    #[inline(always)]
+    fn convert_from(value: &Weekday) -> Self {
+        value.number_days_from_monday()
+    }
+}
+
+impl ConvertFrom<WeekdayNumber> for Weekday {
+    #[inline(always)]
+    fn convert_from(value: WeekdayNumber) -> Self {
+        Weekday::Monday.nth_next(value.into_inner())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -54,15 +176,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn date() {
+        let dates = [
+            Date::MIN,
+            Date::MAX,
+            ordinal_date(2024, 366),
+            ordinal_date(2023, 365),
+        ];
+        for d in dates {
+            assert_eq!(crate::decode::<Date>(&crate::encode(&d)).unwrap(), d);
+        }
+        assert_eq!(
+            crate::encode(&ordinal_date(2024, 60)),
+            crate::encode(&(2024i32, 60u16))
+        );
+
+        assert!(crate::decode::<Date>(&crate::encode(&(2023i32, 366u16))).is_err());
+        assert!(crate::decode::<Date>(&crate::encode(&(2024i32, 0u16))).is_err());
+        assert!(crate::decode::<Date>(&crate::encode(&(2024i32, 367u16))).is_err());
+        let year = Date::MAX.year() + 1;
+        assert!(crate::decode::<Date>(&crate::encode(&(year, 1u16))).is_err());
+    }
+
+    #[test]
+    fn date_time() {
+        let time = Time::from_hms_nano(1, 2, 3, 4).unwrap();
+        let primitive = PrimitiveDateTime::new(ordinal_date(2024, 100), time);
+        let offset = UtcOffset::from_hms(-5, -30, -15).unwrap();
+        let v = (primitive, offset, primitive.assume_offset(offset));
+        assert_eq!(crate::decode::<(_, _, _)>(&crate::encode(&v)).unwrap(), v);
+
+        // Edges of the range are still valid in any offset.
+        let max = PrimitiveDateTime::MAX.assume_offset(UtcOffset::from_hms(-25, -59, -59).unwrap());
+        assert_eq!(
+            crate::decode::<OffsetDateTime>(&crate::encode(&max)).unwrap(),
+            max
+        );
+
+        assert!(crate::decode::<UtcOffset>(&crate::encode(&(25i8, 59i8, 59i8))).is_ok());
+        assert!(crate::decode::<UtcOffset>(&crate::encode(&(26i8, 0i8, 0i8))).is_err());
+        assert!(crate::decode::<UtcOffset>(&crate::encode(&(0i8, -60i8, 0i8))).is_err());
+        assert!(crate::decode::<UtcOffset>(&crate::encode(&(0i8, 0i8, 60i8))).is_err());
+    }
+
+    #[test]
+    fn month_weekday() {
+        for m in (1..=12).map(|m| Month::try_from(m).unwrap()) {
+            assert_eq!(crate::decode::<Month>(&crate::encode(&m)).unwrap(), m);
+        }
+        for w in (0..7).map(|n| Weekday::Monday.nth_next(n)) {
+            assert_eq!(crate::decode::<Weekday>(&crate::encode(&w)).unwrap(), w);
+        }
+        assert!(crate::decode::<Month>(&crate::encode(&0u8)).is_err());
+        assert!(crate::decode::<Month>(&crate::encode(&13u8)).is_err());
+        assert!(crate::decode::<Weekday>(&crate::encode(&7u8)).is_err());
+    }
+
+    fn ordinal_date(year: i32, ordinal: u16) -> Date {
+        Date::from_ordinal_date(year, ordinal).unwrap()
+    }
+
     use alloc::vec::Vec;
-    use time::Time;
-    fn bench_data() -> Vec<Time> {
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+    /// Lets one `bench_data` generate the data for each benchmark.
+    trait BenchData {
+        type Seed;
+        fn from_seed(seed: Self::Seed) -> Self;
+    }
+    impl BenchData for Time {
+        type Seed = (u8, u8, u8, u32);
+        fn from_seed((h, m, s, n): Self::Seed) -> Self {
+            Time::from_hms_nano(h % 24, m % 60, s % 60, n % 1_000_000_000).unwrap()
+        }
+    }
+    impl BenchData for OffsetDateTime {
+        type Seed = (u16, u32, i8);
+        fn from_seed((d, s, o): Self::Seed) -> Self {
+            let date = Date::from_ordinal_date(2000 + (d % 50) as i32, 1 + d % 365).unwrap();
+            let time = Time::from_hms(0, 0, 0).unwrap() + time::Duration::seconds(s as i64);
+            let offset = UtcOffset::from_hms(o % 12, 0, 0).unwrap();
+            date.with_time(time).assume_offset(offset)
+        }
+    }
+    fn bench_data<T: BenchData>() -> Vec<T>
+    where
+        rand::distributions::Standard: rand::distributions::Distribution<T::Seed>,
+    {
         crate::random_data(1000)
             .into_iter()
-            .map(|(h, m, s, n): (u8, u8, u8, u32)| {
-                Time::from_hms_nano(h % 24, m % 60, s % 60, n % 1_000_000_000).unwrap()
-            })
+            .map(T::from_seed)
             .collect()
     }
-    crate::bench_encode_decode!(duration_vec: Vec<_>);
+    crate::bench_encode_decode!(
+        duration_vec: Vec<Time>,
+        offset_date_time_vec: Vec<OffsetDateTime>
+    );
 }
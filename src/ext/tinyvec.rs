@@ -0,0 +1,148 @@
+use crate::coder::{Decoder, Encoder, Result, View};
+use crate::derive::vec::{VecDecoder, VecEncoder};
+use crate::derive::{Decode, Encode};
+use crate::error::err;
+use core::mem::MaybeUninit;
+use tinyvec::{Array, ArrayVec, TinyVec};
+
+impl<A: Array> Encoder<TinyVec<A>> for VecEncoder<A::Item>
+where
+    A::Item: Encode,
+{
+    #[inline(always)]
+    fn encode(&mut self, t: &TinyVec<A>) {
+        self.encode(t.as_slice());
+    }
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a TinyVec<A>> + Clone)
+    where
+        TinyVec<A>: 'a,
+    {
+        self.encode_vectored(i.map(TinyVec::as_slice));
+    }
+}
+impl<A: Array> Encode for TinyVec<A>
+where
+    A::Item: Encode,
+{
+    type Encoder = VecEncoder<A::Item>;
+}
+
+impl<'a, A: Array> Decoder<'a, TinyVec<A>> for VecDecoder<'a, A::Item>
+where
+    A::Item: Decode<'a>,
+{
+    #[inline(always)]
+    fn decode_in_place(&mut self, out: &mut MaybeUninit<TinyVec<A>>) {
+        let n = self.lengths.decode();
+        // Only allocates if `n` doesn't fit inline.
+        let v = out.write(TinyVec::with_capacity(n));
+        v.extend((0..n).map(|_| self.elements.decode()));
+    }
+}
+impl<'a, A: Array> Decode<'a> for TinyVec<A>
+where
+    A::Item: Decode<'a>,
+{
+    type Decoder = VecDecoder<'a, A::Item>;
+}
+
+impl<T: Encode + Default, const N: usize> Encoder<ArrayVec<[T; N]>> for VecEncoder<T> {
+    #[inline(always)]
+    fn encode(&mut self, t: &ArrayVec<[T; N]>) {
+        self.encode(t.as_slice());
+    }
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a ArrayVec<[T; N]>> + Clone)
+    where
+        ArrayVec<[T; N]>: 'a,
+    {
+        self.encode_vectored(i.map(ArrayVec::as_slice));
+    }
+}
+impl<T: Encode + Default, const N: usize> Encode for ArrayVec<[T; N]> {
+    type Encoder = VecEncoder<T>;
+}
+
+pub struct ArrayVecDecoder<'a, T: Decode<'a>, const N: usize>(VecDecoder<'a, T>);
+// Can't derive since it would bound T: Default.
+impl<'a, T: Decode<'a>, const N: usize> Default for ArrayVecDecoder<'a, T, N> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+impl<'a, T: Decode<'a>, const N: usize> View<'a> for ArrayVecDecoder<'a, T, N> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        self.0.populate(input, length)?;
+        // Safety: `length` was same length passed to populate.
+        if unsafe { self.0.lengths.any_greater_than::<N>(length) } {
+            return err("invalid ArrayVec");
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn set_recursion_depth(&mut self, depth: usize) {
+        self.0.set_recursion_depth(depth);
+    }
+}
+impl<'a, T: Decode<'a> + Default, const N: usize> Decoder<'a, ArrayVec<[T; N]>>
+    for ArrayVecDecoder<'a, T, N>
+{
+    #[inline(always)]
+    fn decode_in_place(&mut self, out: &mut MaybeUninit<ArrayVec<[T; N]>>) {
+        let av = out.write(ArrayVec::new());
+        let n = self.0.lengths.decode();
+        // Can't panic since we've ensured self.lengths.max_len() <= N in populate.
+        av.extend((0..n).map(|_| self.0.elements.decode()));
+    }
+}
+impl<'a, T: Decode<'a> + Default, const N: usize> Decode<'a> for ArrayVec<[T; N]> {
+    type Decoder = ArrayVecDecoder<'a, T, N>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use tinyvec::{array_vec, tiny_vec, ArrayVec, TinyVec};
+
+    #[test]
+    fn array_vec() {
+        let v: ArrayVec<[u8; 2]> = array_vec![0, 1];
+        let b = encode(&v);
+        assert_eq!(b, encode(&vec![0u8, 1]));
+        assert!(decode::<ArrayVec<[u8; 1]>>(&b).is_err());
+        assert_eq!(decode::<ArrayVec<[u8; 2]>>(&b).unwrap(), v);
+        assert_eq!(
+            decode::<ArrayVec<[u8; 3]>>(&b).unwrap().as_slice(),
+            v.as_slice()
+        );
+        assert!(decode::<ArrayVec<[u8; 0]>>(&encode(&ArrayVec::<[u8; 0]>::new())).is_ok());
+
+        let v: ArrayVec<[String; 2]> = [String::from("a")].into_iter().collect();
+        assert_eq!(decode::<ArrayVec<[String; 2]>>(&encode(&v)).unwrap(), v);
+    }
+
+    #[test]
+    fn tiny_vec() {
+        let v: Vec<TinyVec<[u8; 4]>> = vec![tiny_vec![1, 2], (0..10).collect()];
+        let encoded = encode(&v);
+        let decoded: Vec<TinyVec<[u8; 4]>> = decode(&encoded).unwrap();
+        assert_eq!(decoded, v);
+        assert!(decoded[0].is_inline());
+        assert!(decoded[1].is_heap());
+    }
+
+    fn bench_data() -> Vec<ArrayVec<[u8; 16]>> {
+        crate::random_data::<u8>(1000)
+            .into_iter()
+            .map(|n| (0..n % 16).collect())
+            .collect()
+    }
+    crate::bench_encode_decode!(tiny_array_vec_vec: Vec<ArrayVec<[u8; 16]>>);
+}
@@ -1,7 +1,8 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::consume::consume_byte_arrays;
 use crate::fast::{FastSlice, NextUnchecked, PushUnchecked, VecImpl};
-use alloc::vec::Vec;
+use crate::output::Output;
+use core::mem::MaybeUninit;
 use core::num::NonZeroUsize;
 
 #[derive(Default)]
@@ -49,54 +50,77 @@ unsafe fn encode_tail(tail: &[f32], mantissa: *mut [u8; 3], sign_exp: *mut u8) {
 }
 
 impl Buffer for F32Encoder {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         let floats = self.0.as_slice();
         let Some(first_float) = floats.get(0).copied() else {
             return;
         };
         let byte_len = core::mem::size_of_val(floats);
-        out.reserve(byte_len);
-        let mantissa_start = out.spare_capacity_mut().as_mut_ptr() as *mut [u8; 3];
-
-        // Safety: we've allocated floats.len() * 4 bytes past the end out of out.
-        // Therefore, the pointer at byte floats.len() * 3 is not past the end of the allocation.
-        let sign_exp_chunks = unsafe { mantissa_start.add(floats.len()) as *mut [u8; CHUNK_SIZE] };
-        let mantissa_chunks = mantissa_start as *mut [[u8; 3]; CHUNK_SIZE];
-
-        let (chunks, tail) = floats.as_chunks::<CHUNK_SIZE>();
-        for (i, chunk) in chunks.iter().enumerate() {
-            // Safety:
-            // `mantissa`: We've allocated floats.len() * 4 bytes, so we have `floats.len() * 4` bytes are valid for writes.
-            //             `floats.len() * 3 + 1 (if tail not empty)` is always <= floats.len() * 4.
-            // `sign_exp`: We've allocated floats.len() * 4 bytes so the pointer starting at floats.len() * 3 has floats.len() valid bytes.
-            //             We keep everying as raw pointers so the aliasing with mantissa's last byte is valid.
+        let write = |bytes: &mut [MaybeUninit<u8>]| {
+            let mantissa_start = bytes.as_mut_ptr() as *mut [u8; 3];
+
+            // Safety: `bytes` has floats.len() * 4 bytes.
+            // Therefore, the pointer at byte floats.len() * 3 is not past the end of `bytes`.
+            let sign_exp_chunks =
+                unsafe { mantissa_start.add(floats.len()) as *mut [u8; CHUNK_SIZE] };
+            let mantissa_chunks = mantissa_start as *mut [[u8; 3]; CHUNK_SIZE];
+
+            let (chunks, tail) = floats.as_chunks::<CHUNK_SIZE>();
+            for (i, chunk) in chunks.iter().enumerate() {
+                // Safety:
+                // `mantissa`: `bytes` has floats.len() * 4 bytes, so `floats.len() * 4` bytes are valid for writes.
+                //             `floats.len() * 3 + 1 (if tail not empty)` is always <= floats.len() * 4.
+                // `sign_exp`: `bytes` has floats.len() * 4 bytes so the pointer starting at floats.len() * 3 has floats.len() valid bytes.
+                //             We keep everying as raw pointers so the aliasing with mantissa's last byte is valid.
+                unsafe {
+                    let mantissa = mantissa_chunks.add(i) as *mut [u8; 3];
+                    let sign_exp = sign_exp_chunks.add(i) as *mut u8;
+                    encode_chunk(chunk, mantissa, sign_exp);
+                }
+            }
+            // Safety: same as above call to encode_chunk.
             unsafe {
-                let mantissa = mantissa_chunks.add(i) as *mut [u8; 3];
-                let sign_exp = sign_exp_chunks.add(i) as *mut u8;
-                encode_chunk(chunk, mantissa, sign_exp);
+                let mantissa = mantissa_chunks.add(chunks.len()) as *mut [u8; 3];
+                let sign_exp = sign_exp_chunks.add(chunks.len()) as *mut u8;
+                encode_tail(tail, mantissa, sign_exp);
             }
-        }
-        // Safety: same as above call to encode_chunk.
-        unsafe {
-            let mantissa = mantissa_chunks.add(chunks.len()) as *mut [u8; 3];
-            let sign_exp = sign_exp_chunks.add(chunks.len()) as *mut u8;
-            encode_tail(tail, mantissa, sign_exp);
-        }
 
-        // Fix up the sign_exp killed by the last 3 byte mantissa writing 4 bytes (technically only required if !chunks.is_empty()).
-        // Safety: sign_exp_chunks is not past the end of the allocation.
-        //         Additionally floats.len() * 3 < floats.len() * 4 because we've ensured
-        //         floats isn't empty, so this 1 byte u8 pointer is inside the allocation.
-        unsafe { *(sign_exp_chunks as *mut u8) = first_float.to_le_bytes()[3] };
-
-        // Safety: We just initialized these elements in the loops above.
-        unsafe { out.set_len(out.len() + byte_len) };
+            // Fix up the sign_exp killed by the last 3 byte mantissa writing 4 bytes (technically only required if !chunks.is_empty()).
+            // Safety: sign_exp_chunks is not past the end of `bytes`.
+            //         Additionally floats.len() * 3 < floats.len() * 4 because we've ensured
+            //         floats isn't empty, so this 1 byte u8 pointer is inside `bytes`.
+            unsafe { *(sign_exp_chunks as *mut u8) = first_float.to_le_bytes()[3] };
+        };
+        // Safety: `write` initializes all `byte_len` bytes.
+        unsafe { out.write_uninit(byte_len, write) };
         self.0.clear();
     }
 
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional.get());
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        core::mem::size_of_val(self.0.as_slice())
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        let len = self.0.len();
+        out.collect(self, len);
+    }
 }
 
 #[derive(Default)]
@@ -117,6 +141,10 @@ impl<'a> View<'a> for F32Decoder<'a> {
         self.sign_exp = sign_exp.into();
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        0
+    }
 }
 
 impl<'a> Decoder<'a, f32> for F32Decoder<'a> {
@@ -132,11 +160,20 @@ impl<'a> Decoder<'a, f32> for F32Decoder<'a> {
         let sign_exp = unsafe { self.sign_exp.next_unchecked() };
         f32::from_bits(mantissa | ((sign_exp as u32) << 24))
     }
+
+    const SEEKABLE: bool = true;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.mantissa.seek(n);
+        self.sign_exp.seek(n);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
     use rand::prelude::*;
     use rand_chacha::ChaCha20Rng;
 
@@ -5,6 +5,11 @@ use core::mem::{ManuallyDrop, MaybeUninit};
 pub type VecImpl<T> = FastVec<T>;
 pub type SliceImpl<'a, T> = FastSlice<'a, T>;
 
+/// Returns the number of bytes allocated by `vec` (but not by its elements).
+pub fn vec_heap_size<T>(vec: &Vec<T>) -> usize {
+    vec.capacity() * core::mem::size_of::<T>()
+}
+
 /// Implementation of [`Vec`] that optimizes push_unchecked at the cost of as_slice being slower.
 pub struct FastVec<T> {
     start: *mut T,    // vec.as_mut_ptr()
@@ -69,6 +74,11 @@ impl<T> FastVec<T> {
         sub_ptr(self.end, self.start)
     }
 
+    /// Returns the number of bytes allocated.
+    pub fn heap_size(&self) -> usize {
+        self.capacity as usize - self.start as usize
+    }
+
     pub fn as_slice(&self) -> &[T] {
         unsafe { core::slice::from_raw_parts(self.start, self.len()) }
     }
@@ -109,6 +119,11 @@ impl<T> FastVec<T> {
         }
     }
 
+    pub fn shrink_to_fit(&mut self) {
+        // Safety: `Vec::shrink_to_fit` panics on OOM without freeing Vec, so Vec is unmodified.
+        unsafe { self.mut_vec(Vec::shrink_to_fit) };
+    }
+
     /// Accesses the [`FastVec`] mutably as a [`Vec`].
     /// # Safety
     /// If `f` panics the [`Vec`] must be unmodified.
@@ -265,6 +280,14 @@ impl<'a, T> FastSlice<'a, T> {
         self.ptr = self.ptr.add(n);
     }
 
+    /// Like [`Self::advance`] but can also move backwards.
+    #[inline(always)]
+    pub unsafe fn seek(&mut self, n: isize) {
+        #[cfg(debug_assertions)]
+        assert!((self.ptr.wrapping_offset(n) as usize) <= self.end as usize);
+        self.ptr = self.ptr.offset(n);
+    }
+
     #[inline(always)]
     pub fn as_ptr(&self) -> *const T {
         self.ptr
@@ -353,6 +376,11 @@ impl<'borrowed, T> CowSlice<'borrowed, T> {
         self.vec
     }
 
+    /// Returns the number of bytes allocated (borrowed elements aren't included).
+    pub fn heap_size(&self) -> usize {
+        vec_heap_size(&self.vec)
+    }
+
     /// References the inner [`SliceImpl`] as a `[T]`.
     /// Safety: `len` must be equal to the slices original len.
     #[must_use]
@@ -389,6 +417,22 @@ impl<'borrowed, T> CowSlice<'borrowed, T> {
         slice
     }
 
+    /// Consumes `length` elements if they're borrowed. Returns `None` if they're owned.
+    /// Safety: `length` must be in bounds.
+    #[inline(always)]
+    pub unsafe fn chunk_borrowed_unchecked(&mut self, length: usize) -> Option<&'borrowed [T]>
+    where
+        T: Copy,
+    {
+        // Owned elements always point into `self.vec` (or to its end), borrowed ones can't. If a
+        // borrowed slice happens to start at the end of `self.vec` it's treated as owned (not unsafe).
+        let owned = self.vec.as_ptr_range();
+        if (owned.start..=owned.end).contains(&self.slice.ptr) {
+            return None;
+        }
+        Some(self.slice.chunk_unchecked(length))
+    }
+
     /// Equivalent to `self.set_owned().extend_from_slice(slice)` but without copying.
     pub fn set_borrowed(&mut self, slice: &'borrowed [T]) {
         self.slice = slice.into();
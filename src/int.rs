@@ -1,8 +1,8 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::error::err;
 use crate::fast::{CowSlice, NextUnchecked, PushUnchecked, SliceImpl, Unaligned, VecImpl};
-use crate::pack_ints::{pack_ints, unpack_ints, Int};
-use alloc::vec::Vec;
+use crate::output::Output;
+use crate::pack_ints::{pack_ints, pack_ints_len, unpack_ints, Int};
 use bytemuck::{CheckedBitPattern, NoUninit, Pod};
 use core::marker::PhantomData;
 use core::num::NonZeroUsize;
@@ -29,7 +29,7 @@ impl<T: Int, P: NoUninit> Encoder<P> for IntEncoder<T> {
 }
 
 impl<T: Int> Buffer for IntEncoder<T> {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         pack_ints(self.0.as_mut_slice(), out);
         self.0.clear();
     }
@@ -37,6 +37,28 @@ impl<T: Int> Buffer for IntEncoder<T> {
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional.get());
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        pack_ints_len(self.0.as_mut_slice())
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+
+    #[cfg(feature = "rayon")]
+    fn collect_into_parallel<'s>(&'s mut self, out: &mut crate::parallel::ParallelOutput<'s>) {
+        let len = self.0.len();
+        out.collect(self, len);
+    }
 }
 
 #[derive(Default)]
@@ -56,6 +78,23 @@ impl<'a, T: Int> View<'a> for IntDecoder<'a, T> {
         unpack_ints::<T>(input, length, &mut self.0)?;
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        tasks.populate(self, input, length, crate::pack_ints::skip_ints::<T>)
+    }
 }
 
 // Makes IntDecoder<u32> able to decode i32/f32 (but not char since it can fail).
@@ -70,6 +109,24 @@ impl<'a, T: Int, P: Pod> Decoder<'a, P> for IntDecoder<'a, T> {
         let v = unsafe { self.0.mut_slice().next_unchecked() };
         bytemuck::must_cast(v)
     }
+
+    const SEEKABLE: bool = true;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        self.0.mut_slice().seek(n);
+    }
+
+    #[inline(always)]
+    unsafe fn decode_borrowed(&mut self, length: usize) -> Option<&'a [P]> {
+        // Only bytes can be borrowed since larger ints in input aren't aligned.
+        if core::mem::size_of::<P>() != 1 {
+            return None;
+        }
+        self.0
+            .chunk_borrowed_unchecked(length)
+            .map(bytemuck::cast_slice)
+    }
 }
 
 /// For NonZeroU32, char, etc.
@@ -100,6 +157,10 @@ where
         }
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
 }
 
 impl<'a, C: CheckedBitPattern + Send + Sync, I: Int> Decoder<'a, C> for CheckedIntDecoder<'a, C, I>
@@ -125,6 +186,13 @@ where
         // Safety: C::Bits and C have the same layout and populate ensured C's bit pattern is valid.
         unsafe { core::mem::transmute_copy(&v) }
     }
+
+    const SEEKABLE: bool = true;
+
+    #[inline(always)]
+    unsafe fn seek(&mut self, n: isize) {
+        Decoder::<I>::seek(&mut self.0, n);
+    }
 }
 
 /// Prevents callers of `ranged_int` from accessing `.0` in the same source file.
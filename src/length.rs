@@ -2,8 +2,8 @@ use crate::coder::{Buffer, Decoder, Encoder, Result, View};
 use crate::error::{err, error};
 use crate::fast::{CowSlice, NextUnchecked, VecImpl};
 use crate::int::{IntDecoder, IntEncoder};
-use crate::pack::{pack_bytes, unpack_bytes};
-use alloc::vec::Vec;
+use crate::output::Output;
+use crate::pack::{pack_bytes, pack_bytes_len, unpack_bytes};
 use core::num::NonZeroUsize;
 
 #[derive(Default)]
@@ -99,7 +99,7 @@ impl LengthEncoder {
 }
 
 impl Buffer for LengthEncoder {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         pack_bytes(self.small.as_mut_slice(), out);
         self.small.clear();
         self.large.collect_into(out);
@@ -108,6 +108,24 @@ impl Buffer for LengthEncoder {
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.small.reserve(additional.get()); // All lengths inhabit small, only large ones inhabit large.
     }
+
+    fn heap_size(&self) -> usize {
+        self.small.heap_size() + self.large.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        pack_bytes_len(self.small.as_slice()) + self.large.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.small.clear();
+        self.large.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.small.shrink_to_fit();
+        self.large.shrink_to_fit();
+    }
 }
 
 #[derive(Default)]
@@ -135,7 +153,10 @@ impl<'a> LengthDecoder<'a> {
 
     /// Returns if any of the decoded lengths are > `N`.
     /// Safety: `length` must be the `length` passed to populate.
-    #[cfg_attr(not(feature = "arrayvec"), allow(unused))]
+    #[cfg_attr(
+        not(any(feature = "arrayvec", feature = "heapless", feature = "tinyvec")),
+        allow(unused)
+    )]
     pub unsafe fn any_greater_than<const N: usize>(&self, length: usize) -> bool {
         if N < 255 {
             // Fast path: don't need to scan large lengths since there shouldn't be any.
@@ -189,6 +210,10 @@ impl<'a> View<'a> for LengthDecoder<'a> {
         self.sum = sum.try_into().map_err(|_| error("length > usize::MAX"))?;
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.small.heap_size() + self.large.heap_size()
+    }
 }
 
 // isize::MAX / (largest type we want to allocate without possibility of overflow)
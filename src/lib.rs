@@ -16,6 +16,7 @@ extern crate test;
 
 mod bool;
 mod buffer;
+mod bytes;
 mod coder;
 mod consume;
 mod derive;
@@ -26,16 +27,22 @@ mod fast;
 mod histogram;
 mod int;
 mod length;
+mod output;
 mod pack;
 mod pack_ints;
 mod pack_shared;
+#[cfg(feature = "rayon")]
+mod parallel;
 mod str;
 mod u8_char;
 
-pub use crate::buffer::Buffer;
+pub use crate::buffer::{Buffer, MemoryUsage};
 pub use crate::derive::*;
 pub use crate::error::Error;
 
+#[cfg(all(feature = "bytes", feature = "std"))]
+pub use crate::ext::bytes::decode_bytes;
+
 #[cfg(feature = "derive")]
 pub use bitcode_derive::{Decode, Encode};
 
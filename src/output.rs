@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+
+/// Where [`Buffer::collect_into`](crate::coder::Buffer::collect_into) writes bytes.
+pub enum Output<'a> {
+    /// Appends to a [`Vec<u8>`], growing it as needed.
+    Vec(&'a mut Vec<u8>),
+    /// Fills the start of the remaining slice. Must be at least
+    /// [`Buffer::encoded_len`](crate::coder::Buffer::encoded_len) bytes or writing will panic.
+    Slice(&'a mut [u8]),
+}
+
+impl<'a> From<&'a mut Vec<u8>> for Output<'a> {
+    fn from(vec: &'a mut Vec<u8>) -> Self {
+        Self::Vec(vec)
+    }
+}
+
+/// Splits off the first `n` bytes of `slice`.
+fn advance<'a>(slice: &mut &'a mut [u8], n: usize) -> &'a mut [u8] {
+    let (bytes, remaining) = core::mem::take(slice).split_at_mut(n);
+    *slice = remaining;
+    bytes
+}
+
+impl Output<'_> {
+    pub fn push(&mut self, byte: u8) {
+        match self {
+            Self::Vec(vec) => vec.push(byte),
+            Self::Slice(slice) => advance(slice, 1)[0] = byte,
+        }
+    }
+
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        match self {
+            Self::Vec(vec) => vec.extend_from_slice(bytes),
+            Self::Slice(slice) => advance(slice, bytes.len()).copy_from_slice(bytes),
+        }
+    }
+
+    pub fn extend(&mut self, bytes: impl IntoIterator<Item = u8>) {
+        match self {
+            Self::Vec(vec) => vec.extend(bytes),
+            Self::Slice(slice) => {
+                for byte in bytes {
+                    advance(slice, 1)[0] = byte;
+                }
+            }
+        }
+    }
+
+    /// Writes `n` bytes by calling `f` on them.
+    /// # Safety
+    /// `f` must initialize all `n` bytes.
+    pub unsafe fn write_uninit(&mut self, n: usize, f: impl FnOnce(&mut [MaybeUninit<u8>])) {
+        match self {
+            Self::Vec(vec) => {
+                vec.reserve(n);
+                f(&mut vec.spare_capacity_mut()[..n]);
+                vec.set_len(vec.len() + n);
+            }
+            Self::Slice(slice) => {
+                let bytes = advance(slice, n);
+                // Safety: `f` only writes initialized bytes, so `bytes` stays initialized.
+                f(&mut *(bytes as *mut [u8] as *mut [MaybeUninit<u8>]));
+            }
+        }
+    }
+}
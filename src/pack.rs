@@ -2,9 +2,11 @@ use crate::coder::Result;
 use crate::consume::{consume_byte, consume_byte_arrays, consume_bytes};
 use crate::error::err;
 use crate::fast::CowSlice;
+use crate::output::Output;
 use crate::pack_ints::{Int, SizedInt};
 use crate::pack_shared::PackingTrait;
 use alloc::vec::Vec;
+use core::mem::MaybeUninit;
 
 /// Possible states per byte in descending order. Each packed byte will use `log2(states)` bits.
 #[repr(u8)]
@@ -32,7 +34,7 @@ impl PackingTrait for Packing {
         }
     }
 
-    fn write<T: crate::pack_ints::SizedUInt>(self, out: &mut Vec<u8>, offset_by_min: bool) {
+    fn write<T: crate::pack_ints::SizedUInt>(self, out: &mut Output, offset_by_min: bool) {
         // pack_ints::Packing needs generics, we only use this on u8 here.
         assert_eq!(core::mem::size_of::<T>(), 1);
         // Encoded in such a way such that 0 is `Self::_256` and higher numbers are smaller packing.
@@ -65,10 +67,15 @@ pub(crate) fn invalid_packing<T>() -> Result<T> {
 }
 
 /// Packs 8 bools per byte.
-pub fn pack_bools(bools: &[bool], out: &mut Vec<u8>) {
+pub fn pack_bools(bools: &[bool], out: &mut Output) {
     pack_arithmetic::<2>(bytemuck::cast_slice(bools), out);
 }
 
+/// Returns the number of bytes [`pack_bools`] writes for `length` bools.
+pub fn pack_bools_len(length: usize) -> usize {
+    packed_len(Packing::_2, length)
+}
+
 /// Unpacks 8 bools per byte. `out` will be overwritten with the bools.
 pub fn unpack_bools(input: &mut &[u8], length: usize, out: &mut CowSlice<bool>) -> Result<()> {
     // TODO could borrow if length == 1.
@@ -94,7 +101,7 @@ impl Byte for i8 {}
 /// avoid confusing bytewise compression algorithms (e.g. Deflate).
 ///
 /// Mutates `bytes` to avoid copying them. The remaining `bytes` should be considered garbage.
-pub fn pack_bytes<T: Byte>(bytes: &mut [T], out: &mut Vec<u8>) {
+pub fn pack_bytes<T: Byte>(bytes: &mut [T], out: &mut Output) {
     if skip_packing(bytes.len()) {
         out.extend_from_slice(bytemuck::must_cast_slice(bytes));
         return;
@@ -111,10 +118,39 @@ pub fn pack_bytes<T: Byte>(bytes: &mut [T], out: &mut Vec<u8>) {
     pack_bytes_unsigned(bytes, out, basic_packing, min_max);
 }
 
+/// Returns the number of bytes [`pack_bytes`] writes for `bytes` without writing them.
+pub fn pack_bytes_len<T: Byte>(bytes: &[T]) -> usize {
+    if skip_packing(bytes.len()) {
+        return bytes.len();
+    }
+    let (basic_packing, min_max) = crate::pack_shared::basic_packing_and_min_max(bytes);
+    let min_max = min_max.map(|(min, max)| (bytemuck::must_cast(min), bytemuck::must_cast(max)));
+    pack_bytes_unsigned_len(bytes.len(), basic_packing, min_max)
+}
+
+/// [`pack_bytes_len`] of `length` bytes which range from `min` to `max`. Used by
+/// [`pack_ints_len`](crate::pack_ints::pack_ints_len) since packing the range is all that matters.
+pub fn pack_bytes_len_min_max(length: usize, min: u8, max: u8) -> usize {
+    if skip_packing(length) {
+        return length;
+    }
+    pack_bytes_unsigned_len(length, Packing::new(max), Some((min, max)))
+}
+
+/// [`pack_bytes_unsigned`] but only returns the number of bytes it writes (including the packing).
+fn pack_bytes_unsigned_len(
+    length: usize,
+    basic_packing: Packing,
+    min_max: Option<(u8, u8)>,
+) -> usize {
+    let (p, min) = crate::pack_shared::choose_offset_packing(length, basic_packing, min_max);
+    1 + min.is_some() as usize + packed_len(p, length)
+}
+
 /// [`pack_bytes`] but after i8s have been cast to u8s.
 fn pack_bytes_unsigned(
     bytes: &mut [u8],
-    out: &mut Vec<u8>,
+    out: &mut Output,
     basic_packing: Packing,
     min_max: Option<(u8, u8)>,
 ) {
@@ -176,8 +212,64 @@ fn unpack_bytes_unsigned<'a>(
     Ok(())
 }
 
+/// Writes the header [`pack_bytes`] writes before `length` bytes it doesn't pack. Followed by the
+/// bytes, it's a valid [`pack_bytes`] output which [`unpack_bytes_borrowed`] can always borrow.
+pub fn pack_bytes_unpacked_header(length: usize, out: &mut Output) {
+    if !skip_packing(length) {
+        Packing::_256.write::<u8>(out, false);
+    }
+}
+
+/// [`pack_bytes_unpacked_header`] but only returns the number of bytes it writes.
+pub fn pack_bytes_unpacked_header_len(length: usize) -> usize {
+    !skip_packing(length) as usize
+}
+
+/// Like [`unpack_bytes`] but borrows the bytes from `input`. Errors if they were packed.
+pub fn unpack_bytes_borrowed<'a>(input: &mut &'a [u8], length: usize) -> Result<&'a [u8]> {
+    if !skip_packing(length) && Packing::read(input)? != (Packing::_256, false) {
+        return err("packed bytes can't be borrowed");
+    }
+    consume_bytes(input, length)
+}
+
+/// Consumes the same bytes as [`unpack_bytes`] without unpacking them.
+#[cfg(feature = "rayon")]
+pub fn skip_bytes(input: &mut &[u8], length: usize) -> Result<()> {
+    if skip_packing(length) {
+        consume_bytes(input, length)?;
+        return Ok(());
+    }
+
+    let (p, offset_by_min) = Packing::read(input)?;
+    if offset_by_min {
+        consume_byte(input)?;
+    }
+    consume_bytes(input, packed_len(p, length))?;
+    Ok(())
+}
+
+/// Consumes the same bytes as [`unpack_bools`] without unpacking them.
+#[cfg(feature = "rayon")]
+pub fn skip_bools(input: &mut &[u8], length: usize) -> Result<()> {
+    consume_bytes(input, pack_bools_len(length))?;
+    Ok(())
+}
+
+/// Number of bytes `length` bytes take up after being packed with `p` (not including the packing).
+fn packed_len(p: Packing, length: usize) -> usize {
+    match p {
+        Packing::_256 => length,
+        Packing::_16 => length.div_ceil(factor_to_divisor::<16>()),
+        Packing::_6 => length.div_ceil(factor_to_divisor::<6>()),
+        Packing::_4 => length.div_ceil(factor_to_divisor::<4>()),
+        Packing::_3 => length.div_ceil(factor_to_divisor::<3>()),
+        Packing::_2 => length.div_ceil(factor_to_divisor::<2>()),
+    }
+}
+
 /// Like `pack_bytes` but all values are less than `N` so it can avoid encoding the packing.
-pub fn pack_bytes_less_than<const N: usize>(bytes: &[u8], out: &mut Vec<u8>) {
+pub fn pack_bytes_less_than<const N: usize>(bytes: &[u8], out: &mut Output) {
     debug_assert!(bytes.iter().all(|&b| (b as usize) < N));
     match Packing::new(N.saturating_sub(1) as u8) {
         Packing::_256 => out.extend_from_slice(bytes),
@@ -189,6 +281,11 @@ pub fn pack_bytes_less_than<const N: usize>(bytes: &[u8], out: &mut Vec<u8>) {
     }
 }
 
+/// Returns the number of bytes [`pack_bytes_less_than`] writes for `length` bytes.
+pub fn pack_bytes_less_than_len<const N: usize>(length: usize) -> usize {
+    packed_len(Packing::new(N.saturating_sub(1) as u8), length)
+}
+
 fn check_less_than_u8<const N: usize, const HISTOGRAM: usize, const FACTOR: usize>(
     unpacked: &[u8],
 ) -> Result<[usize; HISTOGRAM]> {
@@ -392,42 +489,41 @@ fn factor_to_divisor<const FACTOR: usize>() -> usize {
 
 /// Packs multiple bytes into one. All the bytes must be < `FACTOR`.
 /// Factors 2,4,16 are bit packing. Factors 3,6 are arithmetic coding.
-fn pack_arithmetic<const FACTOR: usize>(bytes: &[u8], out: &mut Vec<u8>) {
+fn pack_arithmetic<const FACTOR: usize>(bytes: &[u8], out: &mut Output) {
     debug_assert!(bytes.iter().all(|&v| v < FACTOR as u8));
     let divisor = factor_to_divisor::<FACTOR>();
 
     let floor = bytes.len() / divisor;
     let ceil = (bytes.len() + (divisor - 1)) / divisor;
 
-    out.reserve(ceil);
-    let packed = &mut out.spare_capacity_mut()[..ceil];
-
-    for i in 0..floor {
-        unsafe {
-            packed.get_unchecked_mut(i).write(if FACTOR == 2 {
-                let chunk = u64::from_le_bytes(*(bytes.as_ptr() as *const [u8; 8]).add(i));
-                // https://stackoverflow.com/a/51750902
-                (0x0102040810204080u64.wrapping_mul(chunk) >> 56) as u8
-            } else {
-                let mut acc = 0;
-                for byte_index in 0..divisor {
-                    let byte = *bytes.get_unchecked(i * divisor + byte_index);
-                    acc += byte * (FACTOR as u8).pow(byte_index as u32);
-                }
-                acc
-            });
+    let write = |packed: &mut [MaybeUninit<u8>]| {
+        for i in 0..floor {
+            unsafe {
+                packed.get_unchecked_mut(i).write(if FACTOR == 2 {
+                    let chunk = u64::from_le_bytes(*(bytes.as_ptr() as *const [u8; 8]).add(i));
+                    // https://stackoverflow.com/a/51750902
+                    (0x0102040810204080u64.wrapping_mul(chunk) >> 56) as u8
+                } else {
+                    let mut acc = 0;
+                    for byte_index in 0..divisor {
+                        let byte = *bytes.get_unchecked(i * divisor + byte_index);
+                        acc += byte * (FACTOR as u8).pow(byte_index as u32);
+                    }
+                    acc
+                });
+            }
         }
-    }
-    if floor < ceil {
-        let mut acc = 0;
-        for &v in bytes[floor * divisor..].iter().rev() {
-            acc *= FACTOR as u8;
-            acc += v;
+        if floor < ceil {
+            let mut acc = 0;
+            for &v in bytes[floor * divisor..].iter().rev() {
+                acc *= FACTOR as u8;
+                acc += v;
+            }
+            packed[floor].write(acc);
         }
-        packed[floor].write(acc);
-    }
-    // Safety: `ceil` elements after len were initialized by loops above.
-    unsafe { out.set_len(out.len() + ceil) };
+    };
+    // Safety: `write` initializes all `ceil` bytes.
+    unsafe { out.write_uninit(ceil, write) };
 }
 
 /// Opposite of `pack_arithmetic`. `out` will be overwritten with the unpacked bytes.
@@ -480,6 +576,7 @@ fn unpack_arithmetic<const FACTOR: usize>(
 #[cfg(test)]
 mod tests {
     use crate::error::err;
+    use crate::output::Output;
     use alloc::borrow::ToOwned;
     use alloc::vec::Vec;
     use paste::paste;
@@ -487,7 +584,8 @@ mod tests {
 
     fn pack_bytes<T: super::Byte>(bytes: &[T]) -> Vec<u8> {
         let mut out = vec![];
-        super::pack_bytes(&mut bytes.to_owned(), &mut out);
+        super::pack_bytes(&mut bytes.to_owned(), &mut Output::Vec(&mut out));
+        assert_eq!(super::pack_bytes_len(bytes), out.len());
         out
     }
 
@@ -549,7 +647,7 @@ mod tests {
 
     fn pack_arithmetic<const FACTOR: usize>(bytes: &[u8]) -> Vec<u8> {
         let mut out = vec![];
-        super::pack_arithmetic::<FACTOR>(bytes, &mut out);
+        super::pack_arithmetic::<FACTOR>(bytes, &mut Output::Vec(&mut out));
         out
     }
 
@@ -633,7 +731,7 @@ mod tests {
         let mut out = Vec::with_capacity(bytes.len());
         b.iter(|| {
             out.clear();
-            super::pack_arithmetic::<FACTOR>(&bytes, black_box(&mut out));
+            super::pack_arithmetic::<FACTOR>(&bytes, black_box(&mut Output::Vec(&mut out)));
         });
     }
 
@@ -691,7 +789,7 @@ mod tests {
                 let mut bytes = bytes.clone();
                 bytes[n - 1] = (FACTOR - 1) as u8; // Make least 1 byte is out of bounds.
                 let mut packed = vec![];
-                super::pack_bytes_less_than::<FACTOR>(&bytes, &mut packed);
+                super::pack_bytes_less_than::<FACTOR>(&bytes, &mut Output::Vec(&mut packed));
 
                 assert!(super::unpack_bytes_less_than::<N, 0>(
                     &mut packed.as_slice(),
@@ -708,7 +806,11 @@ mod tests {
             }
 
             let mut packed = vec![];
-            super::pack_bytes_less_than::<N>(&bytes, &mut packed);
+            super::pack_bytes_less_than::<N>(&bytes, &mut Output::Vec(&mut packed));
+            assert_eq!(
+                super::pack_bytes_less_than_len::<N>(bytes.len()),
+                packed.len()
+            );
 
             let mut input = packed.as_slice();
             let mut unpacked = crate::fast::CowSlice::default();
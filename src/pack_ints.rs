@@ -2,7 +2,10 @@ use crate::coder::Result;
 use crate::consume::{consume_byte, consume_byte_arrays};
 use crate::error::error;
 use crate::fast::CowSlice;
-use crate::pack::{invalid_packing, pack_bytes, unpack_bytes};
+use crate::output::Output;
+use crate::pack::{
+    invalid_packing, pack_bytes, pack_bytes_len, pack_bytes_len_min_max, unpack_bytes,
+};
 use crate::pack_shared::PackingTrait;
 use crate::Error;
 use alloc::vec::Vec;
@@ -33,7 +36,7 @@ impl PackingTrait for Packing {
         }
     }
 
-    fn write<T: SizedUInt>(self, out: &mut Vec<u8>, offset_by_min: bool) {
+    fn write<T: SizedUInt>(self, out: &mut Output, offset_by_min: bool) {
         // Encoded in such a way such that 0 is no packing and higher numbers are smaller packing.
         // Also makes no packing with offset_by_min = true is unrepresentable.
         out.push((self as u8 - Self::new(T::MAX) as u8) * 2 - offset_by_min as u8);
@@ -152,14 +155,14 @@ impl_int!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128);
 /// A [`SizedInt`] that is unsigned.
 pub trait SizedUInt: SizedInt + TryInto<u128> {
     fn read(input: &mut &[u8]) -> Result<Self>;
-    fn write(v: Self, out: &mut Vec<u8>);
+    fn write(v: Self, out: &mut Output);
     fn wrapping_add(self, rhs: Self::Une) -> Self::Une;
     fn wrapping_sub(self, rhs: Self) -> Self;
-    fn pack128(v: &[Self], out: &mut Vec<u8>);
-    fn pack64(v: &[Self], out: &mut Vec<u8>);
-    fn pack32(v: &[Self], out: &mut Vec<u8>);
-    fn pack16(v: &[Self], out: &mut Vec<u8>);
-    fn pack8(v: &mut [Self], out: &mut Vec<u8>);
+    fn pack128(v: &[Self], out: &mut Output);
+    fn pack64(v: &[Self], out: &mut Output);
+    fn pack32(v: &[Self], out: &mut Output);
+    fn pack16(v: &[Self], out: &mut Output);
+    fn pack8(v: &mut [Self], out: &mut Output);
     fn unpack128<'a>(v: &'a [[u8; 16]], out: &mut CowSlice<'a, Self::Une>) -> Result<()>;
     fn unpack64<'a>(v: &'a [[u8; 8]], out: &mut CowSlice<'a, Self::Une>) -> Result<()>;
     fn unpack32<'a>(v: &'a [[u8; 4]], out: &mut CowSlice<'a, Self::Une>) -> Result<()>;
@@ -176,7 +179,7 @@ macro_rules! impl_simple {
         fn read(input: &mut &[u8]) -> Result<Self> {
             Ok(Self::from_le_bytes(consume_byte_arrays(input, 1)?[0]))
         }
-        fn write(v: Self, out: &mut Vec<u8>) {
+        fn write(v: Self, out: &mut Output) {
             out.extend_from_slice(&v.to_le_bytes());
         }
         #[inline]
@@ -191,7 +194,7 @@ macro_rules! impl_simple {
 }
 macro_rules! impl_unreachable {
     ($t:ty, $pack:ident, $unpack:ident) => {
-        fn $pack(_: &[Self], _: &mut Vec<u8>) {
+        fn $pack(_: &[Self], _: &mut Output) {
             unreachable!(); // Packings that increase size won't be chosen.
         }
         fn $unpack<'a>(_: &'a [<$t as Int>::Une], _: &mut CowSlice<'a, Self::Une>) -> Result<()> {
@@ -201,7 +204,7 @@ macro_rules! impl_unreachable {
 }
 macro_rules! impl_self {
     ($pack:ident, $unpack:ident) => {
-        fn $pack(v: &[Self], out: &mut Vec<u8>) {
+        fn $pack(v: &[Self], out: &mut Output) {
             // If we're little endian we can copy directly because we encode in little endian.
             if cfg!(target_endian = "little") {
                 out.extend_from_slice(bytemuck::must_cast_slice(&v));
@@ -223,7 +226,7 @@ macro_rules! impl_self {
 }
 macro_rules! impl_smaller {
     ($t:ty, $pack:ident, $unpack:ident) => {
-        fn $pack(v: &[Self], out: &mut Vec<u8>) {
+        fn $pack(v: &[Self], out: &mut Output) {
             out.extend(v.iter().flat_map(|&v| (v as $t).to_le_bytes()))
         }
         fn $unpack<'a>(v: &'a [<$t as Int>::Une], out: &mut CowSlice<'a, Self::Une>) -> Result<()> {
@@ -259,7 +262,7 @@ fn with_scratch<T>(mut f: impl FnMut(&mut Vec<u8>) -> T) -> T {
 
 macro_rules! impl_u8 {
     () => {
-        fn pack8(v: &mut [Self], out: &mut Vec<u8>) {
+        fn pack8(v: &mut [Self], out: &mut Output) {
             with_scratch(|bytes| {
                 bytes.extend(v.iter().map(|&v| v as u8));
                 pack_bytes(bytes, out);
@@ -320,7 +323,7 @@ impl SizedUInt for u8 {
     impl_unreachable!(u32, pack32, unpack32);
     impl_unreachable!(u16, pack16, unpack16);
     // Doesn't use impl_u8!() because it would copy unnecessary.
-    fn pack8(v: &mut [Self], out: &mut Vec<u8>) {
+    fn pack8(v: &mut [Self], out: &mut Output) {
         pack_bytes(v, out);
     }
     fn unpack8<'a>(
@@ -345,12 +348,12 @@ fn skip_packing<T: SizedInt>(length: usize) -> bool {
 }
 
 /// Like [`pack_bytes`] but for larger integers. Handles endian conversion.
-pub fn pack_ints<T: Int>(ints: &mut [T], out: &mut Vec<u8>) {
+pub fn pack_ints<T: Int>(ints: &mut [T], out: &mut Output) {
     T::with_input(ints, |ints| pack_ints_sized(ints, out));
 }
 
 /// [`pack_ints`] but after isize has been converted to i64.
-fn pack_ints_sized<T: SizedInt>(ints: &mut [T], out: &mut Vec<u8>) {
+fn pack_ints_sized<T: SizedInt>(ints: &mut [T], out: &mut Output) {
     // Handle i8 right away since pack_bytes needs to know that it's signed.
     // If we didn't have this special case [0i8, -1, 0, -1, 0, -1] couldn't be packed.
     // Doesn't affect larger signed ints because they're made positive before pack_bytes::<u8> is called.
@@ -369,10 +372,60 @@ fn pack_ints_sized<T: SizedInt>(ints: &mut [T], out: &mut Vec<u8>) {
     pack_ints_sized_unsigned::<T::Unsigned>(ints, out, basic_packing, min_max);
 }
 
+/// Returns the number of bytes [`pack_ints`] writes for `ints` without writing them.
+pub fn pack_ints_len<T: Int>(ints: &mut [T]) -> usize {
+    let mut len = 0;
+    T::with_input(ints, |ints| len = pack_ints_sized_len(ints));
+    len
+}
+
+/// [`pack_ints_len`] but after isize has been converted to i64.
+fn pack_ints_sized_len<T: SizedInt>(ints: &[T]) -> usize {
+    if core::mem::size_of::<T>() == 1 {
+        // Same as pack_ints_sized, u8s are passed through T::pack8 to pack_bytes.
+        return if T::MIN < T::default() {
+            pack_bytes_len::<i8>(bytemuck::must_cast_slice(ints))
+        } else {
+            pack_bytes_len::<u8>(bytemuck::must_cast_slice(ints))
+        };
+    }
+    if skip_packing::<T>(ints.len()) {
+        return core::mem::size_of_val(ints);
+    }
+
+    let (basic_packing, min_max) =
+        crate::pack_shared::basic_packing_and_min_max::<T, Packing>(ints);
+    let (p, min) = crate::pack_shared::choose_offset_packing(ints.len(), basic_packing, min_max);
+    let header = 1 + min.map_or(0, |_| core::mem::size_of::<T>());
+    let n = ints.len();
+    header
+        + match p {
+            Packing::_128 => n * 16,
+            Packing::_64 => n * 8,
+            Packing::_32 => n * 4,
+            Packing::_16 => n * 2,
+            Packing::_8 => {
+                // T::pack8 packs the (offset) ints as bytes, so only their range matters.
+                let (lo, hi) = min_max.unwrap();
+                if let Some(min) = min {
+                    pack_bytes_len_min_max(n, 0, to_u8(hi.wrapping_sub(min)))
+                } else {
+                    pack_bytes_len_min_max(n, to_u8(lo), to_u8(hi))
+                }
+            }
+        }
+}
+
+/// Casts a [`SizedUInt`] that's known to be <= 255 to a `u8`.
+fn to_u8<T: SizedUInt>(v: T) -> u8 {
+    let v: u128 = v.try_into().unwrap_or_else(|_| unreachable!());
+    v as u8
+}
+
 /// [`pack_ints_sized`] but after signed integers have been cast to unsigned.
 fn pack_ints_sized_unsigned<T: SizedUInt>(
     ints: &mut [T],
-    out: &mut Vec<u8>,
+    out: &mut Output,
     basic_packing: Packing,
     min_max: Option<(T, T)>,
 ) {
@@ -437,9 +490,37 @@ fn unpack_ints_sized_unsigned<'a, T: SizedUInt>(
     Ok(())
 }
 
+/// Consumes the same bytes as [`unpack_ints`] without unpacking them.
+#[cfg(feature = "rayon")]
+pub fn skip_ints<T: Int>(input: &mut &[u8], length: usize) -> Result<()> {
+    skip_ints_sized_unsigned::<<T::Int as SizedInt>::Unsigned>(input, length)
+}
+
+/// [`skip_ints`] but after converting to the type [`unpack_ints_sized_unsigned`] unpacks.
+#[cfg(feature = "rayon")]
+fn skip_ints_sized_unsigned<T: SizedUInt>(input: &mut &[u8], length: usize) -> Result<()> {
+    let p = if skip_packing::<T>(length) {
+        Packing::new(T::MAX)
+    } else {
+        let (p, offset_by_min) = Packing::read::<T>(input)?;
+        if offset_by_min {
+            T::read(input)?;
+        }
+        p
+    };
+
+    match p {
+        Packing::_128 => consume_byte_arrays::<16>(input, length).map(drop),
+        Packing::_64 => consume_byte_arrays::<8>(input, length).map(drop),
+        Packing::_32 => consume_byte_arrays::<4>(input, length).map(drop),
+        Packing::_16 => consume_byte_arrays::<2>(input, length).map(drop),
+        Packing::_8 => crate::pack::skip_bytes(input, length),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{usize_too_big, CowSlice, Int, Result};
+    use super::{usize_too_big, CowSlice, Int, Output, Result};
     use crate::error::err;
     use alloc::borrow::ToOwned;
     use alloc::vec::Vec;
@@ -447,8 +528,9 @@ mod tests {
 
     pub fn pack_ints<T: Int>(ints: &[T]) -> Vec<u8> {
         let mut out = vec![];
-        super::pack_ints(&mut ints.to_vec(), &mut out);
+        super::pack_ints(&mut ints.to_vec(), &mut Output::Vec(&mut out));
         assert_eq!(ints, unpack_ints(&out, ints.len()).unwrap());
+        assert_eq!(super::pack_ints_len(&mut ints.to_vec()), out.len());
         out
     }
     pub fn unpack_ints<T: Int>(mut packed: &[u8], length: usize) -> Result<Vec<T>> {
@@ -603,7 +685,7 @@ mod tests {
         b.iter(|| {
             ints.copy_from_slice(&src);
             out.clear();
-            super::pack_ints(black_box(&mut ints), black_box(&mut out));
+            super::pack_ints(black_box(&mut ints), black_box(&mut Output::Vec(&mut out)));
         });
         assert_eq!(out.capacity(), starting_cap);
     }
@@ -1,10 +1,10 @@
+use crate::output::Output;
 use crate::pack_ints::{SizedInt, SizedUInt};
-use alloc::vec::Vec;
 
 pub trait PackingTrait: Copy + PartialOrd {
     fn new<T: SizedUInt>(max: T) -> Self;
 
-    fn write<T: SizedUInt>(self, out: &mut Vec<u8>, offset_by_min: bool);
+    fn write<T: SizedUInt>(self, out: &mut Output, offset_by_min: bool);
 }
 
 fn minmax<T: SizedInt>(v: &[T]) -> (T, T) {
@@ -17,11 +17,23 @@ fn minmax<T: SizedInt>(v: &[T]) -> (T, T) {
     (min, max)
 }
 
+/// The min and max of some ints cast to unsigned, or None if they can't be packed.
+type MinMax<T> = Option<(<T as SizedInt>::Unsigned, <T as SizedInt>::Unsigned)>;
+
 // Writes a packing to `out` iff it returns None.
 pub fn basic_packing_and_signed_min_max_cast_to_unsigned<T: SizedInt, P: PackingTrait>(
     ints: &[T],
-    out: &mut Vec<u8>,
-) -> (P, Option<(T::Unsigned, T::Unsigned)>) {
+    out: &mut Output,
+) -> (P, MinMax<T>) {
+    let (basic_packing, min_max) = basic_packing_and_min_max::<T, P>(ints);
+    if min_max.is_none() {
+        basic_packing.write::<T::Unsigned>(out, false);
+    }
+    (basic_packing, min_max)
+}
+
+/// [`basic_packing_and_signed_min_max_cast_to_unsigned`] without writing anything.
+pub fn basic_packing_and_min_max<T: SizedInt, P: PackingTrait>(ints: &[T]) -> (P, MinMax<T>) {
     // Take a small sample to avoid wastefully scanning the whole slice.
     // Note: This small sample is purely an optimization, it has no impact on the encoded result
     // because we only use it to bail from scanning the entire slice if the first 16-32 elements
@@ -33,7 +45,6 @@ pub fn basic_packing_and_signed_min_max_cast_to_unsigned<T: SizedInt, P: Packing
     // Only have to check packing(max - min) since it's always as good as packing(max).
     let none = P::new(T::Unsigned::MAX);
     if P::new(max.to_unsigned().wrapping_sub(min.to_unsigned())) == none {
-        none.write::<T::Unsigned>(out, false);
         (none, None)
     } else {
         let (remaining_min, remaining_max) = minmax(remaining);
@@ -54,28 +65,39 @@ pub fn basic_packing_and_signed_min_max_cast_to_unsigned<T: SizedInt, P: Packing
 // Writes a packing to `out` iff `min_max` is Some.
 pub fn offset_packing<T: SizedUInt, P: PackingTrait>(
     ints: &mut [T],
-    out: &mut Vec<u8>,
+    out: &mut Output,
     basic_packing: P,
     min_max: Option<(T, T)>,
 ) -> P {
+    let (packing, min) = choose_offset_packing(ints.len(), basic_packing, min_max);
+    if let Some(min) = min {
+        for b in ints.iter_mut() {
+            *b = b.wrapping_sub(min);
+        }
+        packing.write::<T>(out, true);
+        T::write(min, out);
+    } else if min_max.is_some() {
+        packing.write::<T>(out, false);
+    }
+    packing
+}
+
+/// Returns the packing [`offset_packing`] picks for `len` ints and the min it offsets them by (if
+/// any).
+pub fn choose_offset_packing<T: SizedUInt, P: PackingTrait>(
+    len: usize,
+    basic_packing: P,
+    min_max: Option<(T, T)>,
+) -> (P, Option<T>) {
     if let Some((min, max)) = min_max {
         // If subtracting min from all ints results in a better packing do it, otherwise don't bother.
         let offset_packing = P::new(max.wrapping_sub(min));
         // TODO(breaking change) don't hardcode this as 5. Only perform offset_packing
         // on a few elements if the added T::write(min, out) makes it still smaller.
         let small_skip_offset_packing = 5;
-        if offset_packing > basic_packing && ints.len() > small_skip_offset_packing {
-            for b in ints.iter_mut() {
-                *b = b.wrapping_sub(min);
-            }
-            offset_packing.write::<T>(out, true);
-            T::write(min, out);
-            offset_packing
-        } else {
-            basic_packing.write::<T>(out, false);
-            basic_packing
+        if offset_packing > basic_packing && len > small_skip_offset_packing {
+            return (offset_packing, Some(min));
         }
-    } else {
-        basic_packing
     }
+    (basic_packing, None)
 }
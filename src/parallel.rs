@@ -0,0 +1,254 @@
+//! Packs/unpacks large columns on rayon's worker threads. See [`Buffer::encode_parallel`] and
+//! [`Buffer::decode_parallel`](crate::Buffer::decode_parallel).
+//!
+//! Encoders and decoders made of other encoders and decoders (e.g. derived ones) forward
+//! [`Buffer::collect_into_parallel`] and [`View::populate_parallel`] to their fields. Leaves (e.g.
+//! `IntEncoder`) defer their work if they're large enough. Everything else falls back to
+//! [`Buffer::collect_into`] and [`View::populate`], so the output is always the same.
+//!
+//! [`Buffer::encode_parallel`]: crate::Buffer::encode_parallel
+//! [`Buffer::collect_into_parallel`]: crate::coder::Buffer::collect_into_parallel
+//! [`Buffer::collect_into`]: crate::coder::Buffer::collect_into
+//! [`View::populate_parallel`]: crate::coder::View::populate_parallel
+//! [`View::populate`]: crate::coder::View::populate
+use crate::coder::{Buffer, Result, View};
+use crate::consume::expect_eof;
+use crate::output::Output;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use rayon::prelude::*;
+
+/// Columns with fewer values aren't worth sending to another thread.
+pub const MIN_PARALLEL_LEN: usize = 1 << 12;
+
+type Collect<'s> = Box<dyn FnOnce(&mut Vec<u8>) + Send + 's>;
+
+enum Chunk<'s> {
+    Bytes(Vec<u8>),
+    Deferred(Collect<'s>),
+}
+
+/// Output of [`Buffer::collect_into_parallel`](crate::coder::Buffer::collect_into_parallel).
+/// Chunks are concatenated in the order they're added.
+#[derive(Default)]
+pub struct ParallelOutput<'s>(Vec<Chunk<'s>>);
+
+impl<'s> ParallelOutput<'s> {
+    /// Returns the bytes to collect into on the current thread.
+    pub fn bytes(&mut self) -> Output<'_> {
+        if !matches!(self.0.last(), Some(Chunk::Bytes(_))) {
+            self.0.push(Chunk::Bytes(Vec::new()));
+        }
+        let Some(Chunk::Bytes(bytes)) = self.0.last_mut() else {
+            unreachable!();
+        };
+        Output::Vec(bytes)
+    }
+
+    /// Collects `buffer` on a worker thread if it has at least [`MIN_PARALLEL_LEN`] values.
+    pub fn collect<B: Buffer + Send>(&mut self, buffer: &'s mut B, len: usize) {
+        if len < MIN_PARALLEL_LEN {
+            buffer.collect_into(&mut self.bytes());
+        } else {
+            self.0.push(Chunk::Deferred(Box::new(|out| {
+                buffer.collect_into(&mut Output::Vec(out));
+            })));
+        }
+    }
+
+    /// Runs the deferred chunks and appends everything to `out`.
+    pub fn finish(self, out: &mut Vec<u8>) {
+        let chunks: Vec<Vec<u8>> = self
+            .0
+            .into_par_iter()
+            .map(|chunk| match chunk {
+                Chunk::Bytes(bytes) => bytes,
+                Chunk::Deferred(f) => {
+                    let mut bytes = Vec::new();
+                    f(&mut bytes);
+                    bytes
+                }
+            })
+            .collect();
+        out.reserve(chunks.iter().map(Vec::len).sum());
+        for chunk in chunks {
+            out.extend_from_slice(&chunk);
+        }
+    }
+}
+
+/// Work deferred by [`View::populate_parallel`](crate::coder::View::populate_parallel).
+#[derive(Default)]
+pub struct ParallelInput<'s>(Vec<Box<dyn FnOnce() -> Result<()> + Send + 's>>);
+
+impl<'s> ParallelInput<'s> {
+    /// Populates `view` on a worker thread if `length` is at least [`MIN_PARALLEL_LEN`]. `skip`
+    /// must consume the same bytes as [`View::populate`] without doing the work.
+    pub fn populate<'a: 's, V: View<'a> + Send>(
+        &mut self,
+        view: &'s mut V,
+        input: &mut &'a [u8],
+        length: usize,
+        skip: impl FnOnce(&mut &'a [u8], usize) -> Result<()>,
+    ) -> Result<()> {
+        if length < MIN_PARALLEL_LEN {
+            return view.populate(input, length);
+        }
+        let start = *input;
+        skip(input, length)?;
+        let mut column = &start[..start.len() - input.len()];
+        self.defer(move || {
+            view.populate(&mut column, length)?;
+            expect_eof(column)
+        });
+        Ok(())
+    }
+
+    /// Runs `f` (e.g. validation) on a worker thread.
+    pub fn defer(&mut self, f: impl FnOnce() -> Result<()> + Send + 's) {
+        self.0.push(Box::new(f));
+    }
+
+    /// Runs the deferred work, returning an error if any of it failed.
+    pub fn finish(self) -> Result<()> {
+        self.0.into_par_iter().try_for_each(|f| f())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, Buffer, Decode, Encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use std::collections::HashMap;
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    enum Kind {
+        A,
+        B(u16),
+        C { name: String },
+    }
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    struct Entity {
+        id: u64,
+        small: u32,
+        signed: i16,
+        byte: u8,
+        alive: bool,
+        position: [f32; 3],
+        name: String,
+        kind: Kind,
+        parent: Option<u64>,
+        pair: (i64, char),
+        tags: Vec<u8>,
+        #[bitcode(skip)]
+        skipped: u8,
+    }
+
+    fn entities(n: usize) -> Vec<Entity> {
+        crate::random_data::<(u64, u8, i16, bool, [f32; 3], u16)>(n)
+            .into_iter()
+            .enumerate()
+            .map(|(i, (id, byte, signed, alive, position, v))| Entity {
+                id,
+                small: i as u32 % 1000,
+                signed,
+                byte,
+                alive,
+                position,
+                name: if i % 7 == 0 {
+                    "é".repeat(i % 5)
+                } else {
+                    "n".repeat(i % 9)
+                },
+                kind: match byte % 3 {
+                    0 => Kind::A,
+                    1 => Kind::B(v),
+                    _ => Kind::C {
+                        name: "c".repeat(v as usize % 4),
+                    },
+                },
+                parent: alive.then_some(id / 2),
+                pair: (signed as i64 * 1000, char::from(b'a' + byte % 26)),
+                tags: vec![byte % 4; i % 3],
+                skipped: 0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_decode_parallel() {
+        let mut buffer = Buffer::new();
+        for n in [0, 1, 100, 5000, 50000] {
+            let v = entities(n);
+            let bytes = encode(&v);
+            assert_eq!(buffer.encode_parallel(&v), bytes);
+            assert_eq!(buffer.decode_parallel::<Vec<Entity>>(&bytes).unwrap(), v);
+        }
+
+        // Every packing.
+        fn check<T: Encode + for<'a> Decode<'a> + PartialEq + core::fmt::Debug>(v: Vec<T>) {
+            let mut buffer = Buffer::new();
+            let bytes = encode(&v);
+            assert_eq!(buffer.encode_parallel(&v), bytes);
+            assert_eq!(buffer.decode_parallel::<Vec<T>>(&bytes).unwrap(), v);
+        }
+        let n = 10000u64;
+        for max in [1, 2, 3, 5, 15, 255, 65535, u32::MAX as u64, u64::MAX] {
+            check(
+                (0..n)
+                    .map(|i| i.wrapping_mul(0x9E3779B97F4A7C15) % max.saturating_add(1).max(1))
+                    .collect(),
+            );
+            check((0..n).map(|i| 1000 + i % max.min(20)).collect());
+        }
+        check((0..n).map(|i| (i % 3) as i8 - 1).collect());
+        check((0..n).map(|i| i as i64 * -1000).collect());
+        check((0..n).map(|i| i as u128 * u64::MAX as u128).collect());
+        check((0..n).map(|i| i as u16).collect());
+        check((0..n).map(|i| i % 3 == 0).collect());
+
+        let v: HashMap<u32, (Vec<bool>, Option<[u16; 2]>)> = (0..10000)
+            .map(|i| {
+                (
+                    i,
+                    (
+                        vec![i % 3 == 0; i as usize % 3],
+                        (i % 2 == 0).then_some([i as u16; 2]),
+                    ),
+                )
+            })
+            .collect();
+        let bytes = encode(&v);
+        assert_eq!(buffer.encode_parallel(&v), bytes);
+        assert_eq!(buffer.decode_parallel::<HashMap<_, _>>(&bytes).unwrap(), v);
+    }
+
+    #[test]
+    fn decode_parallel_errors() {
+        let mut buffer = Buffer::new();
+        let v = entities(10000);
+        let bytes = encode(&v);
+        for len in (0..bytes.len()).step_by(997).chain([bytes.len() - 1]) {
+            let truncated = &bytes[..len];
+            assert!(decode::<Vec<Entity>>(truncated).is_err());
+            assert!(buffer.decode_parallel::<Vec<Entity>>(truncated).is_err());
+        }
+        let mut extra = bytes.clone();
+        extra.push(0);
+        assert!(buffer.decode_parallel::<Vec<Entity>>(&extra).is_err());
+
+        // Invalid UTF-8 in a large string column.
+        let strings = vec!["abc"; 10000];
+        let mut bytes = encode(&strings);
+        let i = bytes.windows(6).position(|w| w == b"abcabc").unwrap();
+        assert_eq!(
+            buffer.decode_parallel::<Vec<&str>>(&bytes).unwrap(),
+            strings
+        );
+        bytes[i + 15000] = 0xFF;
+        assert!(decode::<Vec<&str>>(&bytes).is_err());
+        assert!(buffer.decode_parallel::<Vec<&str>>(&bytes).is_err());
+    }
+}
@@ -3,6 +3,7 @@ use crate::coder::{Decoder, Result, View};
 use crate::consume::expect_eof;
 use crate::error::{err, error, Error};
 use crate::f32::F32Decoder;
+use crate::fast::vec_heap_size;
 use crate::int::IntDecoder;
 use crate::length::LengthDecoder;
 use crate::serde::guard::guard_zst;
@@ -37,7 +38,7 @@ mod inner {
 }
 pub use inner::deserialize;
 
-enum SerdeDecoder<'a> {
+pub(super) enum SerdeDecoder<'a> {
     Bool(BoolDecoder<'a>),
     Enum((VariantDecoder<'a>, Vec<SerdeDecoder<'a>>)), // (variants, values)
     F32(F32Decoder<'a>),
@@ -61,6 +62,17 @@ impl Default for SerdeDecoder<'_> {
     }
 }
 
+impl<'de> SerdeDecoder<'de> {
+    /// Deserializes the next `T` out of `self`. Populates any decoders it specifies from `input`.
+    #[cfg(feature = "derive")]
+    pub(super) fn deserialize<T: Deserialize<'de>>(&mut self, input: &mut &'de [u8]) -> Result<T> {
+        T::deserialize(DecoderWrapper {
+            decoder: self,
+            input,
+        })
+    }
+}
+
 impl<'a> View<'a> for SerdeDecoder<'a> {
     fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
         match self {
@@ -102,6 +114,30 @@ impl<'a> View<'a> for SerdeDecoder<'a> {
             Self::Unspecified { .. } => unreachable!(),
         }
     }
+
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Bool(d) => d.heap_size(),
+            Self::Enum(d) => {
+                d.0.heap_size()
+                    + vec_heap_size(&d.1)
+                    + d.1.iter().map(Self::heap_size).sum::<usize>()
+            }
+            Self::F32(d) => d.heap_size(),
+            Self::Map(d) => {
+                d.0.heap_size() + size_of_val(&*d.1) + d.1 .0.heap_size() + d.1 .1.heap_size()
+            }
+            Self::Seq(d) => d.0.heap_size() + size_of_val(&*d.1) + d.1.heap_size(),
+            Self::Str(d) => d.heap_size(),
+            Self::Tuple(d) => size_of_val(&**d) + d.iter().map(Self::heap_size).sum::<usize>(),
+            Self::U8(d) => d.heap_size(),
+            Self::U16(d) => d.heap_size(),
+            Self::U32(d) => d.heap_size(),
+            Self::U64(d) => d.heap_size(),
+            Self::U128(d) => d.heap_size(),
+            Self::Unpopulated | Self::Unspecified { .. } => 0,
+        }
+    }
 }
 
 struct DecoderWrapper<'a, 'de> {
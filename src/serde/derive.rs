@@ -0,0 +1,365 @@
+use crate::coder::{Buffer, Decoder, Encoder, Result, View};
+use crate::fast::vec_heap_size;
+use crate::output::Output;
+use crate::serde::de::SerdeDecoder;
+use crate::serde::guard::guard_zst;
+use crate::serde::ser::LazyEncoder;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::num::NonZeroUsize;
+use serde::{Deserialize, Serialize};
+
+/// Encodes `#[bitcode(serde)]` fields with [`Serialize`]. All instances of the field are written as
+/// a nested group of columns (in the same format as [`serialize`][`crate::serialize`]).
+///
+/// Panics if serialization fails since [`Encoder::encode`] can't return an error (see
+/// [`Encode`](crate::Encode#panics)).
+#[derive(Default)]
+pub struct SerializeEncoder {
+    lazy: LazyEncoder,
+    index_alloc: usize,
+}
+
+impl<T: Serialize + ?Sized> Encoder<T> for SerializeEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &T) {
+        // Serde encoders only reserve when they're specified, so reserve 1 in case this is the first
+        // time they're specified. This is cheap if `Buffer::reserve` already reserved enough.
+        self.lazy.reserve_fast(1);
+        self.lazy
+            .serialize(&mut self.index_alloc, t)
+            .expect("#[bitcode(serde)] field failed to serialize");
+    }
+}
+
+impl Buffer for SerializeEncoder {
+    fn collect_into(&mut self, out: &mut Output) {
+        // Serde specifies encoders in the order it visits them which can be different each time, so
+        // we start over instead of reusing them.
+        let index_alloc = core::mem::take(&mut self.index_alloc);
+        core::mem::take(&mut self.lazy).collect_into(index_alloc, out);
+    }
+
+    fn reserve(&mut self, additional: NonZeroUsize) {
+        self.lazy.reserve_fast(additional.get());
+    }
+
+    fn heap_size(&self) -> usize {
+        self.lazy.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.lazy.encoded_len(self.index_alloc)
+    }
+
+    fn clear(&mut self) {
+        self.index_alloc = 0;
+        self.lazy = LazyEncoder::default();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        // collect_into starts over each time, so there's no capacity kept between calls.
+    }
+}
+
+/// Decodes `#[bitcode(serde)]` fields with [`Deserialize`]. Serde decoders consume their input
+/// lazily, so all instances of the field are deserialized up front by [`View::populate`].
+pub struct DeserializeDecoder<'a, T> {
+    // Values before `index` have already been decoded (moved out). The rest may borrow an input
+    // which no longer exists by the time the decoder is reused or dropped (e.g. if another field
+    // failed to populate), so they're leaked instead of dropped.
+    values: Vec<MaybeUninit<T>>,
+    index: usize,
+    _spooky: PhantomData<&'a ()>,
+}
+
+// Can't derive since it would bound T: Default.
+impl<T> Default for DeserializeDecoder<'_, T> {
+    fn default() -> Self {
+        Self {
+            values: Default::default(),
+            index: 0,
+            _spooky: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Deserialize<'a>> View<'a> for DeserializeDecoder<'a, T> {
+    fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
+        guard_zst::<T>(length)?;
+        self.values.clear(); // Leaks any values which weren't decoded.
+        self.index = 0;
+
+        let mut decoder = SerdeDecoder::Unspecified { length };
+        for _ in 0..length {
+            match decoder.deserialize(input) {
+                Ok(t) => self.values.push(MaybeUninit::new(t)),
+                Err(e) => {
+                    // The input is still borrowed here, so the values can be dropped.
+                    // Safety: Every value was initialized by push. Draining removes them first, so
+                    // a panicking drop leaks the rest instead of double dropping.
+                    for v in self.values.drain(..) {
+                        drop(unsafe { v.assume_init() });
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn heap_size(&self) -> usize {
+        vec_heap_size(&self.values)
+    }
+}
+
+impl<'a, T: Deserialize<'a> + Send + Sync> Decoder<'a, T> for DeserializeDecoder<'a, T> {
+    #[inline(always)]
+    fn decode(&mut self) -> T {
+        debug_assert!(self.index < self.values.len());
+        // Safety: populate initialized `length` values and each one is only read once.
+        let t = unsafe { self.values.get_unchecked(self.index).assume_init_read() };
+        self.index += 1;
+        if self.index == self.values.len() {
+            // Every value was moved out, so none of them (which may borrow the input) are kept.
+            self.values.clear();
+            self.index = 0;
+        }
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{decode, encode, Buffer, Decode, Encode};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum SerdeOnly {
+        A(Vec<bool>, u8),
+        B { b: Option<String> },
+    }
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    struct Mixed<'a> {
+        a: u32,
+        #[bitcode(serde)]
+        b: SerdeOnly,
+        #[bitcode(serde)]
+        c: &'a str,
+        d: Vec<u8>,
+    }
+
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    enum MixedEnum {
+        A(#[bitcode(serde)] SerdeOnly),
+        B(u8),
+    }
+
+    #[test]
+    fn serde_field() {
+        let v = vec![
+            Mixed {
+                a: 1,
+                b: SerdeOnly::A(vec![], 2),
+                c: "abc",
+                d: vec![3],
+            },
+            Mixed {
+                a: 4,
+                b: SerdeOnly::B { b: None },
+                c: "",
+                d: vec![],
+            },
+            Mixed {
+                a: 5,
+                b: SerdeOnly::A(vec![true, false], 6),
+                c: "def",
+                d: vec![7, 8],
+            },
+        ];
+        assert_eq!(decode::<Vec<Mixed>>(&encode(&v)).unwrap(), v);
+
+        // Encoders are specified in a different order than the previous call.
+        let v2 = vec![
+            Mixed {
+                a: 1,
+                b: SerdeOnly::B {
+                    b: Some("b".into()),
+                },
+                c: "c",
+                d: vec![],
+            },
+            Mixed {
+                a: 2,
+                b: SerdeOnly::A(vec![true], 3),
+                c: "",
+                d: vec![4],
+            },
+        ];
+        let mut buffer = Buffer::new();
+        for v in [&v, &v2, &v] {
+            let encoded = buffer.encode(v).to_vec();
+            assert_eq!(&buffer.decode::<Vec<Mixed>>(&encoded).unwrap(), v);
+            assert_eq!(buffer.encoded_len(v), encoded.len());
+        }
+
+        let v = vec![
+            MixedEnum::B(1),
+            MixedEnum::A(SerdeOnly::A(vec![false], 2)),
+            MixedEnum::A(SerdeOnly::B { b: None }),
+        ];
+        assert_eq!(decode::<Vec<MixedEnum>>(&encode(&v)).unwrap(), v);
+    }
+
+    #[test]
+    fn serde_field_errors() {
+        let v = vec![MixedEnum::A(SerdeOnly::A(vec![true], 1))];
+        let encoded = encode(&v);
+        assert!(decode::<Vec<MixedEnum>>(&encoded[..encoded.len() - 1]).is_err());
+    }
+
+    #[test]
+    #[should_panic = "#[bitcode(serde)] field failed to serialize"]
+    fn serde_field_unsupported() {
+        #[derive(Serialize)]
+        struct Skips {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            a: Option<u8>,
+        }
+        #[derive(Encode)]
+        struct Outer(#[bitcode(serde)] Skips);
+        encode(&Outer(Skips { a: None }));
+    }
+
+    #[test]
+    fn serde_field_drop() {
+        use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+        static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+        // Counts how many are alive.
+        #[derive(Debug, PartialEq)]
+        struct Counted;
+        impl Counted {
+            fn new() -> Self {
+                LIVE.fetch_add(1, SeqCst);
+                Self
+            }
+        }
+        impl Serialize for Counted {
+            fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.serialize_u8(0)
+            }
+        }
+        impl<'de> Deserialize<'de> for Counted {
+            fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                u8::deserialize(d)?;
+                Ok(Counted::new())
+            }
+        }
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                LIVE.fetch_sub(1, SeqCst);
+            }
+        }
+
+        #[derive(Encode, Decode, Debug, PartialEq)]
+        struct Full {
+            a: u8,
+            #[bitcode(serde)]
+            b: Counted,
+            c: Vec<u8>,
+        }
+        #[derive(Decode, Debug, PartialEq)]
+        #[bitcode(project_from = "Full")]
+        struct Projected {
+            a: u8,
+        }
+        let full = |a| Full {
+            a,
+            b: Counted::new(),
+            c: vec![a],
+        };
+        let v: Vec<Full> = (0..10).map(full).collect();
+        let encoded = encode(&v);
+        let live = LIVE.load(SeqCst);
+
+        // Values are moved out of the decoder, so decoding everything doesn't leak.
+        let mut buffer = Buffer::new();
+        for _ in 0..3 {
+            assert_eq!(buffer.decode::<Vec<Full>>(&encoded).unwrap(), v);
+        }
+        assert_eq!(LIVE.load(SeqCst), live);
+
+        // A later field fails to populate, so the values are leaked.
+        assert!(buffer
+            .decode::<Vec<Full>>(&encoded[..encoded.len() - 1])
+            .is_err());
+        assert!(decode::<Vec<Full>>(&encoded[..encoded.len() - 1]).is_err());
+        assert_eq!(LIVE.load(SeqCst), live + 20);
+
+        // Some values are never decoded, so they're leaked too.
+        assert_eq!(buffer.decode::<Vec<Projected>>(&encoded).unwrap().len(), 10);
+        let mut iter = crate::decode_iter::<Full>(&encoded).unwrap();
+        assert_eq!(iter.next().unwrap().a, 0);
+        drop(iter);
+        let leaked = LIVE.load(SeqCst);
+        assert_eq!(leaked, live + 39);
+
+        // Leaked values are never dropped after the call which populated them.
+        assert_eq!(buffer.decode::<Vec<Full>>(&encoded).unwrap(), v);
+        drop(buffer);
+        assert_eq!(LIVE.load(SeqCst), leaked);
+    }
+
+    #[test]
+    fn serde_field_borrowed_drop() {
+        use core::num::NonZeroU32;
+        use core::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+        static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+        // Reads the input it borrows when dropped.
+        #[derive(Serialize, Deserialize)]
+        struct Loud<'a>(&'a str);
+        impl Drop for Loud<'_> {
+            fn drop(&mut self) {
+                DROPPED.fetch_add(self.0.len(), SeqCst);
+            }
+        }
+
+        #[derive(Encode)]
+        struct Encoded<'a> {
+            #[bitcode(serde)]
+            a: Loud<'a>,
+            b: u32,
+        }
+        #[derive(Decode)]
+        struct Decoded<'a> {
+            #[bitcode(serde)]
+            a: Loud<'a>,
+            #[allow(unused)]
+            b: NonZeroU32,
+        }
+
+        let encoded = |s: &str, b| {
+            encode(&Encoded {
+                a: Loud(&String::from(s)),
+                b,
+            })
+        };
+        let (bytes, other) = (encoded("loud", 0), encoded("louder", 1));
+        let dropped = DROPPED.load(SeqCst);
+
+        // b fails to populate after a has already been populated.
+        let mut buffer = Buffer::new();
+        assert!(buffer.decode::<Decoded>(&bytes).is_err());
+        drop(bytes);
+        drop(buffer.decode::<Decoded>(&other).unwrap());
+        drop(buffer);
+        // Only the successfully decoded value was dropped (the other one was leaked).
+        assert_eq!(DROPPED.load(SeqCst), dropped + "louder".len());
+    }
+}
@@ -4,6 +4,8 @@ use alloc::vec::Vec;
 use core::fmt::Display;
 
 mod de;
+#[cfg(feature = "derive")]
+pub(crate) mod derive;
 mod guard;
 mod ser;
 mod variant;
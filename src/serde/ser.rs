@@ -4,6 +4,7 @@ use crate::error::{err, Error};
 use crate::f32::F32Encoder;
 use crate::int::IntEncoder;
 use crate::length::LengthEncoder;
+use crate::output::Output;
 use crate::serde::variant::VariantEncoder;
 use crate::serde::{default_box_slice, get_mut_or_resize, type_changed};
 use crate::str::StrEncoder;
@@ -39,7 +40,7 @@ mod inner {
 }
 pub use inner::serialize;
 
-enum SpecifiedEncoder {
+pub(super) enum SpecifiedEncoder {
     Bool(BoolEncoder),
     Enum((VariantEncoder, Vec<LazyEncoder>)), // (variants, values)
     F32(F32Encoder),
@@ -93,9 +94,40 @@ impl SpecifiedEncoder {
             Self::U128(v) => v.reserve(additional),
         }
     }
+
+    #[cfg(feature = "derive")]
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Bool(v) => v.heap_size(),
+            Self::Enum(v) => {
+                v.0.heap_size()
+                    + crate::fast::vec_heap_size(&v.1)
+                    + v.1.iter().map(LazyEncoder::heap_size).sum::<usize>()
+            }
+            Self::F32(v) => v.heap_size(),
+            Self::I8(v) => v.heap_size(),
+            Self::I16(v) => v.heap_size(),
+            Self::I32(v) => v.heap_size(),
+            Self::I64(v) => v.heap_size(),
+            Self::I128(v) => v.heap_size(),
+            Self::Map(v) => {
+                v.0.heap_size() + size_of_val(&*v.1) + v.1 .0.heap_size() + v.1 .1.heap_size()
+            }
+            Self::Seq(v) => v.0.heap_size() + size_of_val(&*v.1) + v.1.heap_size(),
+            Self::Str(v) => v.heap_size(),
+            Self::Tuple(v) => {
+                size_of_val(&**v) + v.iter().map(LazyEncoder::heap_size).sum::<usize>()
+            }
+            Self::U8(v) => v.heap_size(),
+            Self::U16(v) => v.heap_size(),
+            Self::U32(v) => v.heap_size(),
+            Self::U64(v) => v.heap_size(),
+            Self::U128(v) => v.heap_size(),
+        }
+    }
 }
 
-enum LazyEncoder {
+pub(super) enum LazyEncoder {
     Unspecified {
         reserved: Option<NonZeroUsize>,
     },
@@ -114,6 +146,13 @@ impl Default for LazyEncoder {
 impl LazyEncoder {
     /// Analogous [`Buffer::collect`], but requires `index_alloc` from serialization.
     fn collect(&mut self, index_alloc: usize) -> Vec<u8> {
+        let mut bytes = vec![];
+        self.collect_into(index_alloc, &mut Output::Vec(&mut bytes));
+        bytes
+    }
+
+    /// Analogous [`Buffer::collect_into`], but requires `index_alloc` from serialization.
+    pub(super) fn collect_into(&mut self, index_alloc: usize, out: &mut Output) {
         // If we just wrote out the buffers in field order we wouldn't be able to deserialize them
         // since we might learn their types from serde in a different order.
         //
@@ -125,11 +164,31 @@ impl LazyEncoder {
         let mut buffers = default_box_slice(index_alloc);
         self.reorder(&mut buffers);
 
-        let mut bytes = vec![];
         for buffer in Vec::from(buffers).into_iter().flatten() {
-            buffer.collect_into(&mut bytes);
+            buffer.collect_into(out);
+        }
+    }
+
+    /// Analogous [`Buffer::encoded_len`], but requires `index_alloc` from serialization.
+    #[cfg(feature = "derive")]
+    pub(super) fn encoded_len(&mut self, index_alloc: usize) -> usize {
+        // The order doesn't matter since we're only adding up the lengths.
+        let mut buffers = default_box_slice(index_alloc);
+        self.reorder(&mut buffers);
+        buffers
+            .iter_mut()
+            .flatten()
+            .map(|buffer| buffer.encoded_len())
+            .sum()
+    }
+
+    /// Analogous [`Buffer::heap_size`].
+    #[cfg(feature = "derive")]
+    pub(super) fn heap_size(&self) -> usize {
+        match self {
+            Self::Specified { specified, .. } => specified.heap_size(),
+            Self::Unspecified { .. } => 0,
         }
-        bytes
     }
 
     fn reorder<'a>(&'a mut self, buffers: &mut [Option<&'a mut dyn Buffer>]) {
@@ -172,6 +231,19 @@ impl LazyEncoder {
         }
     }
 
+    /// Serializes one more `T` into `self`. Only [`LazyEncoder::reserve_fast`]-ed items can be serialized.
+    #[cfg(feature = "derive")]
+    pub(super) fn serialize<T: Serialize + ?Sized>(
+        &mut self,
+        index_alloc: &mut usize,
+        t: &T,
+    ) -> Result<()> {
+        t.serialize(EncoderWrapper {
+            lazy: self,
+            index_alloc,
+        })
+    }
+
     /// OLD COMMENT:
     /// Only reserves if the type is unspecified to save time. Speeds up large 1 time collections
     /// without slowing down many small collections too much. Takes a `usize` instead of a
@@ -179,7 +251,7 @@ impl LazyEncoder {
     ///
     /// Can't be reserve_fast anymore with push_within_capacity.
     #[inline(always)]
-    fn reserve_fast(&mut self, len: usize) {
+    pub(super) fn reserve_fast(&mut self, len: usize) {
         match self {
             Self::Specified { specified, .. } => {
                 if let Some(len) = NonZeroUsize::new(len) {
@@ -1,6 +1,7 @@
 use crate::coder::{Buffer, Decoder, Encoder, Result, View};
-use crate::fast::{CowSlice, NextUnchecked, PushUnchecked, VecImpl};
-use crate::pack::{pack_bytes, unpack_bytes};
+use crate::fast::{vec_heap_size, CowSlice, NextUnchecked, PushUnchecked, VecImpl};
+use crate::output::Output;
+use crate::pack::{pack_bytes, pack_bytes_len, unpack_bytes};
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 use core::num::NonZeroUsize;
@@ -18,7 +19,7 @@ impl Encoder<u8> for VariantEncoder {
 }
 
 impl Buffer for VariantEncoder {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         pack_bytes(self.data.as_mut_slice(), out);
         self.data.clear();
     }
@@ -26,6 +27,22 @@ impl Buffer for VariantEncoder {
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.data.reserve(additional.get());
     }
+
+    fn heap_size(&self) -> usize {
+        self.data.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        pack_bytes_len(self.data.as_slice())
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
 }
 
 #[derive(Default)]
@@ -63,6 +80,10 @@ impl<'a> View<'a> for VariantDecoder<'a> {
         self.histogram.extend_from_slice(&histogram[..len]);
         Ok(())
     }
+
+    fn heap_size(&self) -> usize {
+        self.variants.heap_size() + vec_heap_size(&self.histogram)
+    }
 }
 
 impl<'a> Decoder<'a, u8> for VariantDecoder<'a> {
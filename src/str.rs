@@ -4,10 +4,10 @@ use crate::derive::vec::VecEncoder;
 use crate::error::err;
 use crate::fast::{NextUnchecked, SliceImpl};
 use crate::length::LengthDecoder;
+use crate::output::Output;
 use crate::u8_char::U8Char;
-use alloc::borrow::ToOwned;
+use alloc::borrow::{Cow, ToOwned};
 use alloc::string::String;
-use alloc::vec::Vec;
 use core::num::NonZeroUsize;
 use core::str::{from_utf8, from_utf8_unchecked};
 
@@ -20,13 +20,29 @@ fn str_as_u8_chars(s: &str) -> &[U8Char] {
 }
 
 impl Buffer for StrEncoder {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         self.0.collect_into(out);
     }
 
     fn reserve(&mut self, additional: NonZeroUsize) {
         self.0.reserve(additional);
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.encoded_len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
 }
 
 impl Encoder<str> for StrEncoder {
@@ -72,6 +88,21 @@ impl Encoder<String> for StrEncoder {
     }
 }
 
+impl<'b> Encoder<Cow<'b, str>> for StrEncoder {
+    #[inline(always)]
+    fn encode(&mut self, t: &Cow<'b, str>) {
+        self.encode(t.as_ref());
+    }
+
+    #[inline(always)]
+    fn encode_vectored<'a>(&mut self, i: impl Iterator<Item = &'a Cow<'b, str>> + Clone)
+    where
+        Cow<'b, str>: 'a,
+    {
+        self.encode_vectored(i.map(Cow::as_ref));
+    }
+}
+
 // Doesn't use VecDecoder because can't decode &[u8].
 #[derive(Default)]
 pub struct StrDecoder<'a> {
@@ -84,38 +115,70 @@ impl<'a> View<'a> for StrDecoder<'a> {
     fn populate(&mut self, input: &mut &'a [u8], length: usize) -> Result<()> {
         self.lengths.populate(input, length)?;
         let bytes = consume_bytes(input, self.lengths.length())?;
+        validate(&self.lengths, bytes, length)?;
+        self.strings = bytes.into();
+        Ok(())
+    }
 
-        // Fast path: If bytes are ASCII then they're valid UTF-8 and no char boundary can be invalid.
-        // TODO(optimization):
-        // - Worst case when bytes doesn't fit in CPU cache, this will load bytes 3 times from RAM.
-        // - We should subdivide it into chunks in that case.
-        if is_ascii_simd(bytes)
-            || from_utf8(bytes).is_ok_and(|s| {
-                // length == 0 implies bytes.is_empty() so no char boundaries can be broken. This
-                // early exit allows us to do length.get() - 1 without possibility of overflow.
-                let Some(length) = NonZeroUsize::new(length) else {
-                    debug_assert_eq!(bytes.len(), 0);
-                    return true;
-                };
-                // Check that gaps between individual strings are on char boundaries in larger string.
-                // Boundaries at start and end of `s` aren't checked since s: &str guarantees them.
-                let mut length_decoder = self.lengths.borrowed_clone();
-                let mut end = 0;
-                for _ in 0..length.get() - 1 {
-                    end += length_decoder.decode();
-                    // TODO(optimization) is_char_boundary has unnecessary checks.
-                    if !s.is_char_boundary(end) {
-                        return false;
-                    }
-                }
-                true
-            })
-        {
-            self.strings = bytes.into();
-            Ok(())
-        } else {
-            err("invalid utf8")
+    fn heap_size(&self) -> usize {
+        self.lengths.heap_size()
+    }
+
+    #[cfg(feature = "rayon")]
+    fn populate_parallel<'s>(
+        &'s mut self,
+        input: &mut &'a [u8],
+        length: usize,
+        tasks: &mut crate::parallel::ParallelInput<'s>,
+    ) -> Result<()>
+    where
+        'a: 's,
+    {
+        self.lengths.populate(input, length)?;
+        let bytes = consume_bytes(input, self.lengths.length())?;
+        // Decoding can't start until tasks finish, so strings can be set before they're validated.
+        self.strings = bytes.into();
+        if bytes.len() < crate::parallel::MIN_PARALLEL_LEN {
+            return validate(&self.lengths, bytes, length);
         }
+        let lengths = &self.lengths;
+        tasks.defer(move || validate(lengths, bytes, length));
+        Ok(())
+    }
+}
+
+/// Checks that `bytes` are valid UTF-8 and that each of the `length` strings in them (with lengths
+/// from `lengths`) starts and ends on char boundaries.
+fn validate(lengths: &LengthDecoder<'_>, bytes: &[u8], length: usize) -> Result<()> {
+    // Fast path: If bytes are ASCII then they're valid UTF-8 and no char boundary can be invalid.
+    // TODO(optimization):
+    // - Worst case when bytes doesn't fit in CPU cache, this will load bytes 3 times from RAM.
+    // - We should subdivide it into chunks in that case.
+    if is_ascii_simd(bytes)
+        || from_utf8(bytes).is_ok_and(|s| {
+            // length == 0 implies bytes.is_empty() so no char boundaries can be broken. This
+            // early exit allows us to do length.get() - 1 without possibility of overflow.
+            let Some(length) = NonZeroUsize::new(length) else {
+                debug_assert_eq!(bytes.len(), 0);
+                return true;
+            };
+            // Check that gaps between individual strings are on char boundaries in larger string.
+            // Boundaries at start and end of `s` aren't checked since s: &str guarantees them.
+            let mut length_decoder = lengths.borrowed_clone();
+            let mut end = 0;
+            for _ in 0..length.get() - 1 {
+                end += length_decoder.decode();
+                // TODO(optimization) is_char_boundary has unnecessary checks.
+                if !s.is_char_boundary(end) {
+                    return false;
+                }
+            }
+            true
+        })
+    {
+        Ok(())
+    } else {
+        err("invalid utf8")
     }
 }
 
@@ -131,12 +194,43 @@ impl<'a> Decoder<'a, &'a str> for StrDecoder<'a> {
     }
 }
 
+impl<'a> Decoder<'a, Cow<'a, str>> for StrDecoder<'a> {
+    #[inline(always)]
+    fn decode(&mut self) -> Cow<'a, str> {
+        let v: &'a str = self.decode();
+        Cow::Borrowed(v)
+    }
+}
+
+// Copies directly into the shared allocation instead of converting from a String.
+macro_rules! impl_shared_str {
+    ($(::$ptr: ident)*) => {
+        impl<'a> Decoder<'a, $(::$ptr)*<str>> for StrDecoder<'a> {
+            #[inline(always)]
+            fn decode(&mut self) -> $(::$ptr)*<str> {
+                let v: &str = self.decode();
+                v.into()
+            }
+        }
+    };
+}
+impl_shared_str!(::alloc::rc::Rc);
+#[cfg(target_has_atomic = "ptr")]
+impl_shared_str!(::alloc::sync::Arc);
+
 impl<'a> Decoder<'a, String> for StrDecoder<'a> {
     #[inline(always)]
     fn decode(&mut self) -> String {
         let v: &str = self.decode();
         v.to_owned()
     }
+
+    #[inline(always)]
+    fn decode_into(&mut self, out: &mut String) {
+        let v: &str = self.decode();
+        out.clear();
+        out.push_str(v);
+    }
 }
 
 /// Tests 128 bytes a time instead of `<[u8]>::is_ascii` which only tests 8.
@@ -164,7 +258,9 @@ mod tests {
     use super::is_ascii_simd;
     use crate::u8_char::U8Char;
     use crate::{decode, encode};
-    use alloc::borrow::ToOwned;
+    use alloc::borrow::{Cow, ToOwned};
+    use alloc::string::String;
+    use alloc::vec::Vec;
     use test::{black_box, Bencher};
 
     #[test]
@@ -184,6 +280,31 @@ mod tests {
         assert_eq!(decode::<[&str; 2]>(&encode(&[c, c])).unwrap(), [c, c]);
     }
 
+    #[test]
+    fn cow_str() {
+        let v: Vec<Cow<str>> = vec!["abc".into(), String::from("☺").into(), "".into()];
+        let encoded = encode(&v);
+        assert_eq!(encoded, encode(&vec!["abc", "☺", ""]));
+        let decoded = decode::<Vec<Cow<str>>>(&encoded).unwrap();
+        assert_eq!(decoded, v);
+        assert!(decoded.iter().all(|s| matches!(s, Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn shared_str() {
+        use alloc::boxed::Box;
+        use alloc::rc::Rc;
+        use alloc::sync::Arc;
+
+        let v: (Rc<str>, Arc<str>, Box<str>) = ("abc".into(), "☺".into(), "".into());
+        let encoded = encode(&v);
+        assert_eq!(encoded, encode(&("abc", "☺", "")));
+        assert_eq!(
+            decode::<(Rc<str>, Arc<str>, Box<str>)>(&encoded).unwrap(),
+            v
+        );
+    }
+
     #[test]
     fn test_is_ascii_simd() {
         assert!(is_ascii_simd(&[0x7F; 128]));
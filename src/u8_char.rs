@@ -1,7 +1,7 @@
 use crate::coder::{Buffer, Encoder};
 use crate::derive::Encode;
 use crate::fast::VecImpl;
-use alloc::vec::Vec;
+use crate::output::Output;
 use core::num::NonZeroUsize;
 
 /// Represents a single byte of a string, unlike u8 which represents an integer.
@@ -33,7 +33,7 @@ impl Encoder<U8Char> for U8CharEncoder {
 }
 
 impl Buffer for U8CharEncoder {
-    fn collect_into(&mut self, out: &mut Vec<u8>) {
+    fn collect_into(&mut self, out: &mut Output) {
         out.extend_from_slice(bytemuck::must_cast_slice(self.0.as_slice()));
         self.0.clear();
     }
@@ -41,4 +41,20 @@ impl Buffer for U8CharEncoder {
     fn reserve(&mut self, _: NonZeroUsize) {
         unimplemented!(); // StrEncoder only uses Encoder::as_primitive.
     }
+
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+
+    fn encoded_len(&mut self) -> usize {
+        self.0.len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
 }